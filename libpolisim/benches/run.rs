@@ -0,0 +1,34 @@
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use libpolisim::sim::{Simulator, gen_random_congress, gen_random_proposal};
+
+/// Rounds per simulated run
+const ROUNDS: usize = 10;
+
+/// One round should cost O(E + N), so time per run ought to grow linearly
+/// in both the member count and the average degree.
+fn bench_run(c: &mut Criterion) {
+    let mut group = c.benchmark_group("run");
+    group.sample_size(10);
+    for n in [100, 1_000, 10_000] {
+        for avg_degree in [5, 20] {
+            let density = avg_degree as f64 / (n - 1) as f64;
+            let congress = gen_random_congress(n, 4, 8, density, 42);
+            let proposal = gen_random_proposal(8, 1.0);
+            group.bench_with_input(
+                BenchmarkId::new(format!("degree {}", avg_degree), n),
+                &congress,
+                |b, congress| {
+                    b.iter(|| {
+                        let mut sim = Simulator::new(congress, proposal.clone());
+                        sim.run(ROUNDS, 0.1);
+                        sim.tally()
+                    })
+                },
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_run);
+criterion_main!(benches);
@@ -0,0 +1,826 @@
+use std::collections::{BTreeMap, HashMap};
+
+use nalgebra::DVector;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+use crate::sim::{
+    CongressGraph, Majority, SimError, SimulationResult, Simulator, VoteTally, gen_random_proposal,
+    gen_random_proposal_with_rng,
+};
+
+/// Run one simulation and return whether it passes together with the
+/// net support (sum of final scores) used as a continuous objective.
+/// Runs in fixed update order, so nearby proposals differ only by the
+/// proposal and finite differences are not swamped by order noise.
+fn evaluate(
+    cg: &CongressGraph,
+    proposal: &DVector<f64>,
+    rounds: usize,
+    threshold: f64,
+    rule: Majority,
+) -> (bool, f64) {
+    let mut sim = Simulator::new(cg, proposal.clone());
+    sim.run_ordered(rounds, threshold);
+    let net_support = cg.graph.node_indices().map(|idx| sim.get_score(idx)).sum();
+    (sim.passes(rule), net_support)
+}
+
+/// Search for a passing proposal near a failing one.
+///
+/// Each iteration estimates the gradient of net YES support (the sum of
+/// final scores) with forward finite differences of size `step`, then moves
+/// the proposal `step` along the normalized gradient. Returns the first
+/// passing proposal, the input itself if it already passes, or `None` once
+/// `max_iters` is exhausted or the gradient vanishes. Every simulation runs
+/// in fixed update order, so the search is deterministic.
+pub fn nearest_passing_direction(
+    cg: &CongressGraph,
+    proposal: &DVector<f64>,
+    rounds: usize,
+    threshold: f64,
+    rule: Majority,
+    step: f64,
+    max_iters: usize,
+) -> Option<DVector<f64>> {
+    let mut current = proposal.clone();
+    let (mut passed, mut support) = evaluate(cg, &current, rounds, threshold, rule);
+
+    for _ in 0..max_iters {
+        if passed {
+            return Some(current);
+        }
+
+        let mut gradient = DVector::zeros(current.len());
+        for dim in 0..current.len() {
+            let mut probe = current.clone();
+            probe[dim] += step;
+            let (_, probe_support) = evaluate(cg, &probe, rounds, threshold, rule);
+            gradient[dim] = (probe_support - support) / step;
+        }
+
+        let norm = gradient.norm();
+        if norm < f64::EPSILON {
+            return None;
+        }
+        current += gradient * (step / norm);
+        (passed, support) = evaluate(cg, &current, rounds, threshold, rule);
+    }
+
+    if passed { Some(current) } else { None }
+}
+
+/// Fraction of `trials` random proposals (entries in `[-range, range]`)
+/// that pass under `rule`. Returns 0.0 for an empty graph or zero trials.
+pub fn monte_carlo_pass_rate(
+    cg: &CongressGraph,
+    range: f64,
+    trials: usize,
+    rounds: usize,
+    threshold: f64,
+    rule: Majority,
+) -> f64 {
+    let Some(dim) = cg.ideal_dimension() else {
+        return 0.0;
+    };
+    if trials == 0 {
+        return 0.0;
+    }
+
+    let passed = (0..trials)
+        .filter(|_| {
+            let mut sim = Simulator::new(cg, gen_random_proposal(dim, range));
+            sim.run(rounds, threshold);
+            sim.passes(rule)
+        })
+        .count();
+    passed as f64 / trials as f64
+}
+
+/// Simulate `trials` random proposals (entries in `[-range, range]`) and hand
+/// each result to `callback` as soon as it completes, so memory stays flat
+/// regardless of trial count. Proposals and update orders are drawn from one
+/// RNG seeded with `seed`, making the whole stream reproducible.
+#[allow(clippy::too_many_arguments)]
+pub fn for_each_random_proposal<F: FnMut(&SimulationResult)>(
+    cg: &CongressGraph,
+    trials: usize,
+    range: f64,
+    rounds: usize,
+    threshold: f64,
+    rule: Majority,
+    seed: u64,
+    mut callback: F,
+) {
+    let Some(dim) = cg.ideal_dimension() else {
+        return;
+    };
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    for _ in 0..trials {
+        let proposal = gen_random_proposal_with_rng(dim, range, &mut rng);
+        let mut sim = Simulator::new(cg, proposal);
+        sim.run_with_rng(rounds, threshold, &mut rng);
+        callback(&sim.result(rule));
+    }
+}
+
+/// Fraction of `trials` seeded random proposals on which members `a_id` and
+/// `b_id` cast the same non-abstain vote. Returns 0.0 if either ID is
+/// unknown or `trials` is zero.
+#[allow(clippy::too_many_arguments)]
+pub fn pairwise_agreement(
+    cg: &CongressGraph,
+    a_id: &str,
+    b_id: &str,
+    range: f64,
+    rounds: usize,
+    threshold: f64,
+    trials: usize,
+    seed: u64,
+) -> f64 {
+    if trials == 0 {
+        return 0.0;
+    }
+
+    let mut agreed = 0usize;
+    // The rule only affects `passed`, which is ignored here
+    for_each_random_proposal(
+        cg,
+        trials,
+        range,
+        rounds,
+        threshold,
+        Majority::SIMPLE,
+        seed,
+        |result| {
+            let a = result.votes.get(a_id);
+            if a.is_some_and(|&v| v != 0) && a == result.votes.get(b_id) {
+                agreed += 1;
+            }
+        },
+    );
+    agreed as f64 / trials as f64
+}
+
+/// Per-member differences between two graphs voting on the same proposal
+#[derive(Debug, Clone)]
+pub struct ComparisonReport {
+    /// Vote under graph A and graph B by member ID; `None` if the member is
+    /// absent from that graph
+    pub votes: BTreeMap<String, (Option<i8>, Option<i8>)>,
+    /// IDs whose vote differs, including members present in only one graph
+    pub differing: Vec<String>,
+    pub passed_a: bool,
+    pub passed_b: bool,
+}
+
+impl ComparisonReport {
+    /// True if the proposal passes under one graph but not the other
+    pub fn outcome_flipped(&self) -> bool {
+        self.passed_a != self.passed_b
+    }
+}
+
+/// Run the same proposal through two graphs (e.g. before and after a member
+/// switches parties) and diff the final votes, matching members by ID.
+/// Both sides draw their update order from an RNG seeded with `seed`, so
+/// differences come from the graph change rather than from shuffle noise.
+pub fn compare_outcomes(
+    cg_a: &CongressGraph,
+    cg_b: &CongressGraph,
+    proposal: &DVector<f64>,
+    rounds: usize,
+    threshold: f64,
+    rule: Majority,
+    seed: u64,
+) -> ComparisonReport {
+    let mut sim_a = Simulator::new(cg_a, proposal.clone());
+    sim_a.run_with_rng(rounds, threshold, &mut StdRng::seed_from_u64(seed));
+    let mut sim_b = Simulator::new(cg_b, proposal.clone());
+    sim_b.run_with_rng(rounds, threshold, &mut StdRng::seed_from_u64(seed));
+
+    let mut votes: BTreeMap<String, (Option<i8>, Option<i8>)> = BTreeMap::new();
+    for (id, vote) in sim_a.get_votes() {
+        votes.entry(id).or_default().0 = Some(vote);
+    }
+    for (id, vote) in sim_b.get_votes() {
+        votes.entry(id).or_default().1 = Some(vote);
+    }
+    let differing = votes
+        .iter()
+        .filter(|(_, (a, b))| a != b)
+        .map(|(id, _)| id.clone())
+        .collect();
+
+    ComparisonReport {
+        votes,
+        differing,
+        passed_a: sim_a.passes(rule),
+        passed_b: sim_b.passes(rule),
+    }
+}
+
+/// Outcome of one proposal in a batch evaluation
+#[derive(Debug, Clone)]
+pub struct ProposalResult {
+    /// Position of the proposal in the input slice
+    pub index: usize,
+    pub tally: VoteTally,
+    pub passed: bool,
+    pub social_welfare: f64,
+}
+
+/// Evaluate many proposals against the same graph, each on a fresh simulator.
+/// Proposal `i` draws its update order from an RNG seeded with `seed + i`,
+/// so every row is reproducible on its own.
+pub fn evaluate_proposals(
+    cg: &CongressGraph,
+    proposals: &[DVector<f64>],
+    rounds: usize,
+    threshold: f64,
+    rule: Majority,
+    seed: u64,
+) -> Vec<ProposalResult> {
+    proposals
+        .iter()
+        .enumerate()
+        .map(|(index, proposal)| {
+            let mut rng = StdRng::seed_from_u64(seed.wrapping_add(index as u64));
+            let mut sim = Simulator::new(cg, proposal.clone());
+            sim.run_with_rng(rounds, threshold, &mut rng);
+            ProposalResult {
+                index,
+                tally: sim.tally(),
+                passed: sim.passes(rule),
+                social_welfare: sim.social_welfare(),
+            }
+        })
+        .collect()
+}
+
+/// Index of the proposal that beats every other one head-to-head, or `None`
+/// if there is no such proposal (a cycle or a tie).
+///
+/// Each proposal is simulated once (proposal `i` seeded with `seed + i`) and
+/// a member prefers whichever of two proposals leaves them with the higher
+/// final score. A proposal beats another when strictly more members prefer
+/// it; members scoring both equally are indifferent.
+pub fn condorcet_winner(
+    cg: &CongressGraph,
+    proposals: &[DVector<f64>],
+    rounds: usize,
+    threshold: f64,
+    seed: u64,
+) -> Option<usize> {
+    let scores: Vec<Vec<f64>> = proposals
+        .iter()
+        .enumerate()
+        .map(|(index, proposal)| {
+            let mut rng = StdRng::seed_from_u64(seed.wrapping_add(index as u64));
+            let mut sim = Simulator::new(cg, proposal.clone());
+            sim.run_with_rng(rounds, threshold, &mut rng);
+            cg.graph
+                .node_indices()
+                .map(|idx| sim.get_score(idx))
+                .collect()
+        })
+        .collect();
+
+    let beats = |a: usize, b: usize| {
+        let prefer_a = scores[a]
+            .iter()
+            .zip(&scores[b])
+            .filter(|(x, y)| x > y)
+            .count();
+        let prefer_b = scores[a]
+            .iter()
+            .zip(&scores[b])
+            .filter(|(x, y)| x < y)
+            .count();
+        prefer_a > prefer_b
+    };
+
+    (0..proposals.len()).find(|&a| (0..proposals.len()).all(|b| a == b || beats(a, b)))
+}
+
+/// Index of the proposal surviving a runoff, where each elimination round
+/// simulates every surviving proposal afresh for `rounds_per_vote` rounds
+/// and drops the one with the fewest yes votes, until one remains.
+///
+/// Among proposals tied for the fewest yes votes, the one listed last in
+/// `proposals` is eliminated, so earlier proposals win ties. Each vote runs
+/// in fixed update order (`Simulator::run_ordered`), so the same inputs
+/// always give the same winner. `proposals` must not be empty.
+pub fn run_runoff(
+    cg: &CongressGraph,
+    proposals: &[DVector<f64>],
+    rounds_per_vote: usize,
+    threshold: f64,
+) -> usize {
+    assert!(
+        !proposals.is_empty(),
+        "a runoff needs at least one proposal"
+    );
+    let mut surviving: Vec<usize> = (0..proposals.len()).collect();
+    while surviving.len() > 1 {
+        let yes_counts: Vec<usize> = surviving
+            .iter()
+            .map(|&index| {
+                let mut sim = Simulator::new(cg, proposals[index].clone());
+                sim.run_ordered(rounds_per_vote, threshold);
+                sim.tally().yes
+            })
+            .collect();
+        let fewest = *yes_counts.iter().min().expect("surviving is not empty");
+        let loser = yes_counts
+            .iter()
+            .rposition(|&count| count == fewest)
+            .expect("the minimum is present");
+        surviving.remove(loser);
+    }
+    surviving[0]
+}
+
+/// Upper bound on the number of coalitions `minimal_winning_coalitions`
+/// enumerates, since their count grows combinatorially with the body size
+pub const MAX_COALITIONS: usize = 10_000;
+
+/// Minimal winning coalitions among the members voting yes on `proposal`.
+///
+/// A coalition of yes-voters is winning if the proposal still passes under
+/// `rule` when every other yes-voter switches to no (no-voters and
+/// abstainers keep their votes), and minimal if removing any member makes
+/// it losing. Votes are unweighted, so these are exactly the subsets of the
+/// smallest passing size. They are listed in lexicographic order of sorted
+/// member IDs, and enumeration stops after `MAX_COALITIONS`, so on large
+/// bodies the result is a prefix of the full list. Empty if the proposal
+/// fails.
+pub fn minimal_winning_coalitions(
+    cg: &CongressGraph,
+    proposal: &DVector<f64>,
+    rounds: usize,
+    threshold: f64,
+    rule: Majority,
+) -> Vec<Vec<String>> {
+    let mut sim = Simulator::new(cg, proposal.clone());
+    sim.run(rounds, threshold);
+    let tally = sim.tally();
+
+    let mut yes_voters: Vec<String> = cg
+        .members()
+        .filter(|&(idx, _)| sim.get_vote(idx) == 1)
+        .map(|(_, member)| member.id.clone())
+        .collect();
+    yes_voters.sort();
+
+    let n = yes_voters.len();
+    let passes_with = |size: usize| {
+        VoteTally {
+            yes: size,
+            no: tally.no + (n - size),
+            abstain: tally.abstain,
+        }
+        .passes(rule)
+    };
+    let Some(size) = (1..=n).find(|&size| passes_with(size)) else {
+        return Vec::new();
+    };
+
+    // Walk the size-element index combinations in lexicographic order
+    let mut coalitions = Vec::new();
+    let mut picks: Vec<usize> = (0..size).collect();
+    loop {
+        coalitions.push(picks.iter().map(|&i| yes_voters[i].clone()).collect());
+        if coalitions.len() >= MAX_COALITIONS {
+            break;
+        }
+        let Some(pos) = (0..size).rev().find(|&pos| picks[pos] < n - size + pos) else {
+            break;
+        };
+        picks[pos] += 1;
+        for next in pos + 1..size {
+            picks[next] = picks[next - 1] + 1;
+        }
+    }
+    coalitions
+}
+
+/// Krehbiel's gridlock interval for a one-dimensional congress: the range
+/// of status-quo points that no proposal can displace under `rule`.
+///
+/// Members are treated as spatial voters at their ideal point who vote yes
+/// on a change only if it brings the outcome more than `threshold` closer
+/// to them. With `k` the smallest yes count that passes among all `n`
+/// members and positions sorted ascending, the pivots are the `k`-th member
+/// from the right and from the left, giving
+/// `[x[n - k] - threshold, x[k - 1] + threshold]`. Pivots are counted over
+/// the whole body, so rules that ignore abstentions are treated like their
+/// absolute counterparts. Returns `None` unless the ideal dimension is 1.
+pub fn gridlock_interval(cg: &CongressGraph, rule: Majority, threshold: f64) -> Option<(f64, f64)> {
+    if cg.ideal_dimension() != Some(1) {
+        return None;
+    }
+    let mut positions: Vec<f64> = cg.graph.node_weights().map(|n| n.ideal[0]).collect();
+    positions.sort_by(|a, b| a.total_cmp(b));
+
+    let n = positions.len();
+    let k = match rule {
+        Majority::SIMPLE | Majority::ABSSIMPLE => n / 2 + 1,
+        Majority::SUPER | Majority::ABSSUPER => 2 * n / 3 + 1,
+        Majority::UNANIMITY => n,
+        Majority::WeightedAbsSuper { fraction, .. } | Majority::Custom { fraction, .. } => {
+            ((fraction * n as f64).floor() as usize + 1).min(n)
+        }
+    };
+    Some((positions[n - k] - threshold, positions[k - 1] + threshold))
+}
+
+/// The median member of a one-dimensional congress, the pivotal voter of
+/// the median voter theorem. With an even number of members the lower of
+/// the two middle members is returned; members at the same position are
+/// ordered by ID. Members carry no vote weights, so each counts once.
+/// Returns `None` unless the ideal dimension is 1.
+pub fn median_member(cg: &CongressGraph) -> Option<String> {
+    if cg.ideal_dimension() != Some(1) {
+        return None;
+    }
+    let mut positions: Vec<(f64, &str)> = cg
+        .graph
+        .node_weights()
+        .map(|n| (n.ideal[0], n.id.as_str()))
+        .collect();
+    positions.sort_by(|a, b| a.0.total_cmp(&b.0).then_with(|| a.1.cmp(b.1)));
+    Some(positions[(positions.len() - 1) / 2].1.to_string())
+}
+
+/// Width of the discipline interval `min_discipline_for_pass` narrows down
+/// to before returning its upper end
+pub const DISCIPLINE_TOLERANCE: f64 = 1e-3;
+
+/// The smallest discipline for `party` under which `proposal` passes,
+/// found by binary search over `[0, 1]` with every other setting as
+/// configured.
+///
+/// Each probe overrides the party's discipline (see
+/// `Simulator::set_party_discipline`) and runs in fixed update order, so the
+/// answer is deterministic. The search assumes more discipline never turns
+/// a pass into a failure, and the result overshoots the true minimum by at
+/// most `DISCIPLINE_TOLERANCE`. Returns `Some(0.0)` if the proposal passes
+/// without any discipline and `None` if it fails even at full discipline.
+pub fn min_discipline_for_pass(
+    cg: &CongressGraph,
+    party: &str,
+    proposal: &DVector<f64>,
+    rounds: usize,
+    threshold: f64,
+    rule: Majority,
+) -> Result<Option<f64>, SimError> {
+    let passes_at = |discipline: f64| -> Result<bool, SimError> {
+        let mut sim = Simulator::new(cg, proposal.clone());
+        sim.set_party_discipline(party, discipline)?;
+        sim.run_ordered(rounds, threshold);
+        Ok(sim.passes(rule))
+    };
+
+    if passes_at(0.0)? {
+        return Ok(Some(0.0));
+    }
+    if !passes_at(1.0)? {
+        return Ok(None);
+    }
+    let (mut failing, mut passing) = (0.0, 1.0);
+    while passing - failing > DISCIPLINE_TOLERANCE {
+        let mid = (failing + passing) / 2.0;
+        if passes_at(mid)? {
+            passing = mid;
+        } else {
+            failing = mid;
+        }
+    }
+    Ok(Some(passing))
+}
+
+/// Shapley values of each member's contribution to the social welfare of
+/// the proposal, keyed by member ID.
+///
+/// The welfare of a coalition is the total policy alignment of its members
+/// with the proposal, as in `Simulator::social_welfare`. That game is
+/// additive: a member adds their own alignment to any coalition they join,
+/// whatever the ordering, so each Shapley value is exactly the member's
+/// alignment and no sampling is needed. The values sum to the grand
+/// coalition's welfare.
+pub fn welfare_shapley(cg: &CongressGraph, proposal: &DVector<f64>) -> HashMap<String, f64> {
+    cg.members()
+        .map(|(_, m)| (m.id.clone(), cg.alignment(&m.ideal, proposal)))
+        .collect()
+}
+
+/// Pass/fail along one policy axis: proposals hold the other dimensions at
+/// `fixed_dims` (one value per remaining dimension, in order) and sweep
+/// dimension `sweep_dim` over `steps` evenly spaced values from `-range` to
+/// `range` inclusive, revealing the winning region along that axis. A
+/// single step samples 0.0. Each point is simulated as by
+/// `evaluate_proposals`, point `i` seeded with `seed + i`, and reported as
+/// `(swept value, passed)`.
+///
+/// Panics if `sweep_dim` or the length of `fixed_dims` does not fit the
+/// congress's ideal dimension.
+#[allow(clippy::too_many_arguments)]
+pub fn pass_map_1d(
+    cg: &CongressGraph,
+    fixed_dims: &[f64],
+    sweep_dim: usize,
+    range: f64,
+    steps: usize,
+    rounds: usize,
+    threshold: f64,
+    rule: Majority,
+    seed: u64,
+) -> Vec<(f64, bool)> {
+    let dim = fixed_dims.len() + 1;
+    assert!(
+        sweep_dim < dim,
+        "sweep_dim {} is out of range for {} dimensions",
+        sweep_dim,
+        dim
+    );
+
+    let values: Vec<f64> = match steps {
+        1 => vec![0.0],
+        _ => (0..steps)
+            .map(|i| -range + 2.0 * range * i as f64 / (steps - 1) as f64)
+            .collect(),
+    };
+    let proposals: Vec<DVector<f64>> = values
+        .iter()
+        .map(|&value| {
+            let mut entries = fixed_dims.to_vec();
+            entries.insert(sweep_dim, value);
+            DVector::from_vec(entries)
+        })
+        .collect();
+
+    evaluate_proposals(cg, &proposals, rounds, threshold, rule, seed)
+        .into_iter()
+        .map(|result| (values[result.index], result.passed))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sim::{Node, Party, gen_random_congress};
+    use petgraph::graph::NodeIndex;
+
+    fn member(id: &str, ideal: &[f64], bias: f64, swing: f64) -> Node {
+        Node {
+            id: id.into(),
+            ideal: DVector::from_row_slice(ideal),
+            bias,
+            swing,
+            credibility: 1.0,
+            threshold: None,
+            conflict_abstain: None,
+            initial_vote: None,
+        }
+    }
+
+    fn congress(members: Vec<Node>) -> CongressGraph {
+        let mut cg = CongressGraph::new();
+        for m in members {
+            cg.add_node(m);
+        }
+        cg
+    }
+
+    #[test]
+    fn nearest_passing_direction_finds_nearby_pass() {
+        // One yes, one abstain, one no: 50% of votes cast fails a simple majority
+        let cg = congress(vec![
+            member("a", &[1.0, 0.0], 0.0, 0.0),
+            member("b", &[0.0, 1.0], 0.0, 0.0),
+            member("c", &[0.0, 1.0], -0.2, 0.0),
+        ]);
+        let proposal = DVector::from_vec(vec![1.0, 0.0]);
+        let mut sim = Simulator::new(&cg, proposal.clone());
+        sim.run_ordered(3, 0.1);
+        assert!(!sim.passes(Majority::SIMPLE));
+
+        let found = nearest_passing_direction(&cg, &proposal, 3, 0.1, Majority::SIMPLE, 0.1, 20)
+            .expect("a passing proposal is close by");
+        assert!((&found - &proposal).norm() < 0.5);
+        let mut sim = Simulator::new(&cg, found);
+        sim.run_ordered(3, 0.1);
+        assert!(sim.passes(Majority::SIMPLE));
+    }
+
+    #[test]
+    fn identical_members_always_agree() {
+        let cg = congress(vec![
+            member("a", &[0.6, -0.8], 0.0, 0.0),
+            member("b", &[0.6, -0.8], 0.0, 0.0),
+            member("c", &[-0.6, 0.8], 0.0, 0.0),
+        ]);
+        // Only proposals both abstain on (nearly orthogonal ones) are missed
+        assert!(pairwise_agreement(&cg, "a", "b", 1.0, 3, 0.05, 50, 7) > 0.9);
+        assert_eq!(pairwise_agreement(&cg, "a", "c", 1.0, 3, 0.05, 50, 7), 0.0);
+        assert_eq!(
+            pairwise_agreement(&cg, "a", "nobody", 1.0, 3, 0.05, 50, 7),
+            0.0
+        );
+    }
+
+    #[test]
+    fn evaluate_proposals_matches_individual_runs() {
+        let cg = gen_random_congress(12, 2, 2, 0.3, 1);
+        let proposals: Vec<DVector<f64>> = (0..5)
+            .map(|i| DVector::from_vec(vec![i as f64 - 2.0, 1.0]))
+            .collect();
+
+        let results = evaluate_proposals(&cg, &proposals, 4, 0.1, Majority::SIMPLE, 9);
+        assert_eq!(results.len(), proposals.len());
+        for (i, result) in results.iter().enumerate() {
+            let mut sim = Simulator::new(&cg, proposals[i].clone());
+            sim.run_with_rng(4, 0.1, &mut StdRng::seed_from_u64(9 + i as u64));
+            assert_eq!(result.index, i);
+            assert_eq!(result.passed, sim.passes(Majority::SIMPLE));
+            assert_eq!(result.tally, sim.tally());
+        }
+    }
+
+    #[test]
+    fn condorcet_winner_is_the_compromise() {
+        let cg = congress(vec![
+            member("left", &[1.0, 0.0], 0.0, 0.0),
+            member("center", &[0.8, 0.6], 0.0, 0.0),
+            member("right", &[0.0, 1.0], 0.0, 0.0),
+        ]);
+        let proposals = vec![
+            DVector::from_vec(vec![1.0, 0.0]),
+            DVector::from_vec(vec![1.0, 0.5]),
+            DVector::from_vec(vec![0.0, 1.0]),
+        ];
+        assert_eq!(condorcet_winner(&cg, &proposals, 3, 0.1, 0), Some(1));
+
+        // Identical proposals tie, so neither beats the other
+        let twins = vec![proposals[0].clone(), proposals[0].clone()];
+        assert_eq!(condorcet_winner(&cg, &twins, 3, 0.1, 0), None);
+    }
+
+    /// Members at the given 1-D positions, listed out of order
+    fn line(positions: &[f64]) -> CongressGraph {
+        congress(
+            positions
+                .iter()
+                .enumerate()
+                .map(|(i, &x)| member(&format!("m{}", i), &[x], 0.0, 0.5))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn gridlock_interval_between_pivots() {
+        let cg = line(&[2.0, -1.0, 0.0, -2.0, 1.0]);
+        // Simple majority of 5 needs 3: both pivots are the median at 0.0
+        assert_eq!(
+            gridlock_interval(&cg, Majority::SIMPLE, 0.1),
+            Some((-0.1, 0.1))
+        );
+        // Two thirds of 5 needs 4: pivots at -1.0 and 1.0
+        assert_eq!(
+            gridlock_interval(&cg, Majority::SUPER, 0.1),
+            Some((-1.1, 1.1))
+        );
+
+        let cg = congress(vec![member("a", &[1.0, 0.0], 0.0, 0.5)]);
+        assert_eq!(gridlock_interval(&cg, Majority::SIMPLE, 0.1), None);
+    }
+
+    #[test]
+    fn median_member_of_a_line() {
+        assert_eq!(
+            median_member(&line(&[2.0, -1.0, 0.5, -2.0, 1.0])),
+            Some("m2".into())
+        );
+        // Even size: the lower of the middle members, at 2.0
+        assert_eq!(
+            median_member(&line(&[3.0, 1.0, 2.0, 4.0])),
+            Some("m2".into())
+        );
+        let cg = congress(vec![member("a", &[1.0, 0.0], 0.0, 0.5)]);
+        assert_eq!(median_member(&cg), None);
+    }
+
+    #[test]
+    fn welfare_shapley_sums_to_social_welfare() {
+        let cg = gen_random_congress(12, 2, 3, 0.3, 4);
+        let proposal = DVector::from_vec(vec![0.2, -0.5, 0.8]);
+        let values = welfare_shapley(&cg, &proposal);
+
+        let welfare = Simulator::new(&cg, proposal.clone()).social_welfare();
+        assert_eq!(values.len(), 12);
+        assert!((values.values().sum::<f64>() - welfare).abs() < 1e-9);
+        for (_, m) in cg.members() {
+            assert_eq!(values[&m.id], cg.alignment(&m.ideal, &proposal));
+        }
+    }
+
+    #[test]
+    fn pass_map_1d_sweeps_one_axis() {
+        let cg = congress(
+            (0..3)
+                .map(|i| member(&format!("m{}", i), &[1.0, 0.0], 0.0, 0.5))
+                .collect(),
+        );
+        let map = pass_map_1d(&cg, &[0.0], 0, 1.0, 5, 0, 0.1, Majority::SIMPLE, 1);
+        assert_eq!(map.len(), 5);
+        let swept: Vec<f64> = map.iter().map(|&(v, _)| v).collect();
+        assert_eq!(swept, vec![-1.0, -0.5, 0.0, 0.5, 1.0]);
+        let passed: Vec<bool> = map.iter().map(|&(_, p)| p).collect();
+        assert_eq!(passed, vec![false, false, false, true, true]);
+
+        assert_eq!(
+            pass_map_1d(&cg, &[0.0], 0, 1.0, 1, 0, 0.1, Majority::SIMPLE, 1),
+            vec![(0.0, false)]
+        );
+    }
+
+    #[test]
+    fn compare_outcomes_reports_only_the_party_switch() {
+        let proposal = DVector::from_vec(vec![1.0]);
+        let members = || {
+            congress(vec![
+                member("y1", &[1.0], 0.0, 0.5),
+                member("y2", &[1.0], 0.0, 0.5),
+                member("n1", &[-1.0], 0.0, 0.5),
+                member("n2", &[-1.0], 0.0, 0.5),
+                member("s", &[0.0], 0.0, 1.0),
+            ])
+        };
+        let with_parties = |yes: &[usize], no: &[usize]| {
+            let mut cg = members();
+            for (id, roster) in [("yes", yes), ("no", no)] {
+                cg.add_party(Party {
+                    id: id.into(),
+                    discipline: 1.0,
+                    members: roster.iter().copied().map(NodeIndex::new).collect(),
+                    position: None,
+                });
+            }
+            cg
+        };
+        // The swing member `s` crosses from the yes party to the no party
+        let before = with_parties(&[0, 1, 4], &[2, 3]);
+        let after = with_parties(&[0, 1], &[2, 3, 4]);
+
+        let report = compare_outcomes(&before, &after, &proposal, 5, 0.1, Majority::SIMPLE, 3);
+        assert_eq!(report.differing, vec!["s".to_string()]);
+        assert_eq!(report.votes["s"], (Some(1), Some(-1)));
+        assert!(report.passed_a && !report.passed_b);
+        assert!(report.outcome_flipped());
+
+        // An unchanged graph never differs from itself
+        let cg = gen_random_congress(30, 3, 2, 0.2, 8);
+        let proposal = DVector::from_vec(vec![0.1, -0.3]);
+        let report = compare_outcomes(&cg, &cg, &proposal, 10, 0.05, Majority::SIMPLE, 3);
+        assert!(report.differing.is_empty());
+    }
+
+    #[test]
+    fn runoff_eliminates_fewest_yes_and_last_listed_on_ties() {
+        // Two members per axis and one in between who backs both axes
+        let cg = congress(vec![
+            member("x1", &[1.0, 0.0], 0.0, 0.5),
+            member("x2", &[1.0, 0.0], 0.0, 0.5),
+            member("y1", &[0.0, 1.0], 0.0, 0.5),
+            member("y2", &[0.0, 1.0], 0.0, 0.5),
+            member("xy", &[1.0, 1.0], 0.0, 0.5),
+        ]);
+        let x = DVector::from_vec(vec![1.0, 0.0]);
+        let y = DVector::from_vec(vec![0.0, 1.0]);
+        let away = DVector::from_vec(vec![-1.0, -1.0]);
+
+        // `away` (0 yes) goes first, then x and y tie on 3 yes each and the
+        // later-listed one is eliminated
+        let proposals = [x.clone(), y.clone(), away.clone()];
+        assert_eq!(run_runoff(&cg, &proposals, 0, 0.1), 0);
+        let proposals = [away, y, x];
+        assert_eq!(run_runoff(&cg, &proposals, 0, 0.1), 1);
+
+        // The fewest yes votes lose even when listed first
+        let narrow = DVector::from_vec(vec![0.0, 1.0]);
+        let broad = DVector::from_vec(vec![1.0, 0.2]);
+        assert_eq!(run_runoff(&cg, &[narrow, broad], 0, 0.1), 1);
+    }
+
+    #[test]
+    fn runoff_is_deterministic() {
+        let cg = gen_random_congress(30, 3, 2, 0.2, 6);
+        let proposals: Vec<DVector<f64>> = (0..4)
+            .map(|i| DVector::from_vec(vec![(i as f64 - 1.5) / 2.0, 0.3]))
+            .collect();
+        let winner = run_runoff(&cg, &proposals, 10, 0.05);
+        assert!((0..5).all(|_| run_runoff(&cg, &proposals, 10, 0.05) == winner));
+    }
+}
@@ -1,2 +1,5 @@
+pub mod analysis;
 pub mod loader;
 pub mod sim;
+#[cfg(feature = "wasm")]
+pub mod wasm;
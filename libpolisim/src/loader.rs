@@ -1,28 +1,330 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 
 use nalgebra::DVector;
+use petgraph::algo::connected_components;
 use petgraph::graph::NodeIndex;
 use serde::Deserialize;
 
-use crate::sim::{CongressGraph, Node, Party};
+use crate::sim::{CongressGraph, DimensionKind, Node, Party};
+
+/// Options controlling how strictly a config is validated while loading.
+#[derive(Debug, Clone)]
+pub struct LoaderOptions {
+    /// Abort on party members that do not name a known congress member.
+    /// When false, such references are dropped and reported as warnings.
+    pub strict_party_members: bool,
+    /// Resolve edge and party member references ignoring ASCII case.
+    /// Exact matches still win; a reference that only matches members
+    /// differing by case (e.g. `A1` and `a1`) is an error.
+    pub case_insensitive_refs: bool,
+}
+
+impl Default for LoaderOptions {
+    fn default() -> Self {
+        LoaderOptions {
+            strict_party_members: true,
+            case_insensitive_refs: false,
+        }
+    }
+}
+
+/// Maps member references in edges and parties to node indices
+struct IdResolver {
+    exact: HashMap<String, NodeIndex>,
+    folded: Option<HashMap<String, Vec<NodeIndex>>>,
+}
+
+impl IdResolver {
+    fn new(case_insensitive: bool) -> Self {
+        IdResolver {
+            exact: HashMap::new(),
+            folded: case_insensitive.then(HashMap::new),
+        }
+    }
+
+    fn insert(&mut self, id: String, idx: NodeIndex) {
+        if let Some(folded) = &mut self.folded {
+            folded.entry(id.to_ascii_lowercase()).or_default().push(idx);
+        }
+        self.exact.insert(id, idx);
+    }
+
+    /// Ok(None) if no member matches, Err if the match is ambiguous
+    fn resolve(&self, id: &str) -> Result<Option<NodeIndex>, String> {
+        if let Some(&idx) = self.exact.get(id) {
+            return Ok(Some(idx));
+        }
+        let Some(folded) = &self.folded else {
+            return Ok(None);
+        };
+        match folded.get(&id.to_ascii_lowercase()).map(Vec::as_slice) {
+            None | Some([]) => Ok(None),
+            Some([idx]) => Ok(Some(*idx)),
+            Some(_) => Err(format!(
+                "Reference `{}` is ambiguous: several members match it ignoring case",
+                id
+            )),
+        }
+    }
+}
+
+/// Newest config format version this loader understands
+pub const CONFIG_VERSION: u32 = 1;
 
 /// Top‐level TOML structure with members, parties, and edges.
 #[derive(Deserialize)]
 struct RawConfig {
+    /// Config format version; 1 if absent
+    version: Option<u32>,
     ideal_dimension: usize,
     congress_members: Vec<RawMember>,
     parties: Vec<RawParty>,
     edges: Option<Vec<RawEdge>>,
+    dimension_kinds: Option<Vec<DimensionKind>>,
+    binary_weight: Option<f64>,
+    /// CSV file (relative to the config) holding ideal rows for `ideal_ref`
+    ideals_csv: Option<String>,
+    /// Coalitions of member IDs that vote together on their mean score
+    vote_blocks: Option<Vec<Vec<String>>>,
 }
 
 #[derive(Deserialize)]
 struct RawMember {
     id: String,
-    ideal: Vec<f64>,
+    ideal: Option<RawIdeal>,
+    /// Zero-based data row of `ideals_csv` to use instead of `ideal`
+    ideal_ref: Option<usize>,
     bias: f64,
     swing: f64,
+    credibility: Option<f64>,
+    /// Personal decision threshold, overriding the run's threshold
+    threshold: Option<f64>,
+    /// Tolerance for conflict with the party before abstaining
+    conflict_abstain: Option<f64>,
+    /// Declared starting position, overriding alignment and bias
+    initial_vote: Option<i8>,
+}
+
+/// An ideal point, either as a full array or as a sparse
+/// `{ dimension_index = value }` table with missing dimensions neutral (0.0).
+/// An empty array `ideal = []` marks an undecided member with no ideology
+/// of their own: a zero ideal, aligned 0.0 with every proposal. Such a
+/// member must have bias 0.0, so they start at 0.0 and take their position
+/// entirely from peer and party pressure.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RawIdeal {
+    Dense(Vec<f64>),
+    Sparse(HashMap<String, f64>),
+}
+
+impl RawIdeal {
+    /// Expand into a dense vector of length `dim`
+    fn to_dense(&self, member_id: &str, dim: usize) -> Result<Vec<f64>, String> {
+        match self {
+            RawIdeal::Dense(values) if values.is_empty() => Ok(vec![0.0; dim]),
+            RawIdeal::Dense(values) => {
+                if values.len() != dim {
+                    return Err(format!(
+                        "Member `{}` has ideal length {}, but ideal_dimension = {}",
+                        member_id,
+                        values.len(),
+                        dim
+                    ));
+                }
+                Ok(values.clone())
+            }
+            RawIdeal::Sparse(entries) => {
+                let mut values = vec![0.0; dim];
+                for (key, value) in entries {
+                    let index: usize = key.parse().map_err(|_| {
+                        format!(
+                            "Member `{}` has non-numeric ideal dimension `{}`",
+                            member_id, key
+                        )
+                    })?;
+                    if index >= dim {
+                        return Err(format!(
+                            "Member `{}` sets ideal dimension {}, but ideal_dimension = {}",
+                            member_id, index, dim
+                        ));
+                    }
+                    values[index] = *value;
+                }
+                Ok(values)
+            }
+        }
+    }
+}
+
+impl RawMember {
+    /// The member's ideal as a dense vector of length `dim`
+    fn dense_ideal(&self, dim: usize) -> Result<Vec<f64>, String> {
+        let values = match &self.ideal {
+            Some(RawIdeal::Dense(values)) if values.is_empty() && self.bias != 0.0 => {
+                return Err(format!(
+                    "Member `{}` is undecided (`ideal = []`), so bias must be 0.0, got {}",
+                    self.id, self.bias
+                ));
+            }
+            Some(ideal) => ideal.to_dense(&self.id, dim)?,
+            None => {
+                return Err(format!(
+                    "Member `{}` has neither `ideal` nor `ideal_ref`",
+                    self.id
+                ));
+            }
+        };
+        if let Some(v) = values.iter().find(|v| !v.is_finite()) {
+            return Err(format!(
+                "Member `{}` has non-finite ideal component {}",
+                self.id, v
+            ));
+        }
+        Ok(values)
+    }
+
+    /// Reject NaN or infinite numbers, which would silently poison scores,
+    /// and an initial vote that is not a vote
+    fn check_finite(&self) -> Result<(), String> {
+        let fields = [
+            ("bias", Some(self.bias)),
+            ("swing", Some(self.swing)),
+            ("credibility", self.credibility),
+            ("threshold", self.threshold),
+            ("conflict_abstain", self.conflict_abstain),
+        ];
+        for (name, value) in fields {
+            if let Some(v) = value.filter(|v| !v.is_finite()) {
+                return Err(format!(
+                    "Member `{}` has non-finite {} {}",
+                    self.id, name, v
+                ));
+            }
+        }
+        if let Some(vote) = self.initial_vote.filter(|v| !(-1..=1).contains(v)) {
+            return Err(format!(
+                "Member `{}` has initial_vote {}, which must be -1, 0 or 1",
+                self.id, vote
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Reject a NaN or infinite edge weight
+fn check_edge_weight(e: &RawEdge) -> Result<(), String> {
+    if e.weight.is_finite() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Edge `{}` -> `{}` has non-finite weight {}",
+            e.from, e.to, e.weight
+        ))
+    }
+}
+
+/// Replace every member's `ideal_ref` with the referenced `ideals_csv` row
+fn resolve_ideal_refs(raw: &mut RawConfig, base_dir: &Path) -> Result<(), String> {
+    if raw.congress_members.iter().all(|rm| rm.ideal_ref.is_none()) {
+        return Ok(());
+    }
+    let csv_path = raw
+        .ideals_csv
+        .as_ref()
+        .ok_or("Members use `ideal_ref`, but no `ideals_csv` is set")?;
+    let rows = read_ideals_csv(&base_dir.join(csv_path))?;
+
+    for rm in &mut raw.congress_members {
+        let Some(row) = rm.ideal_ref else {
+            continue;
+        };
+        if rm.ideal.is_some() {
+            return Err(format!(
+                "Member `{}` sets both `ideal` and `ideal_ref`",
+                rm.id
+            ));
+        }
+        let values = rows.get(row).ok_or_else(|| {
+            format!(
+                "Member `{}` has ideal_ref {}, but `{}` has {} rows",
+                rm.id,
+                row,
+                csv_path,
+                rows.len()
+            )
+        })?;
+        rm.ideal = Some(RawIdeal::Dense(values.clone()));
+    }
+    Ok(())
+}
+
+/// Read `from,to,weight` rows; a first line whose weight is not a number is
+/// treated as a header
+fn read_edges_csv(path: &Path) -> Result<Vec<RawEdge>, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read `{}`: {}", path.display(), e))?;
+
+    let mut edges = Vec::new();
+    for (line_no, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let [from, to, weight] = fields[..] else {
+            return Err(format!(
+                "Expected `from,to,weight` on line {} of `{}`",
+                line_no + 1,
+                path.display()
+            ));
+        };
+        match weight.parse() {
+            Ok(weight) => edges.push(RawEdge {
+                from: from.to_string(),
+                to: to.to_string(),
+                weight,
+                active_dimensions: None,
+            }),
+            Err(_) if line_no == 0 => continue, // header
+            Err(_) => {
+                return Err(format!(
+                    "Invalid weight on line {} of `{}`",
+                    line_no + 1,
+                    path.display()
+                ));
+            }
+        }
+    }
+    Ok(edges)
+}
+
+/// Read rows of comma-separated floats. A first line that is not numeric is
+/// treated as a header and skipped.
+fn read_ideals_csv(path: &Path) -> Result<Vec<Vec<f64>>, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read `{}`: {}", path.display(), e))?;
+
+    let mut rows = Vec::new();
+    for (line_no, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let parsed: Result<Vec<f64>, _> = line.split(',').map(|v| v.trim().parse()).collect();
+        match parsed {
+            Ok(row) => rows.push(row),
+            Err(_) if line_no == 0 => continue, // header
+            Err(_) => {
+                return Err(format!(
+                    "Invalid number on line {} of `{}`",
+                    line_no + 1,
+                    path.display()
+                ));
+            }
+        }
+    }
+    Ok(rows)
 }
 
 #[derive(Deserialize)]
@@ -30,6 +332,56 @@ struct RawParty {
     id: String,
     discipline: f64,
     members: Vec<String>,
+    /// Official stance the whip pushes toward instead of the member average
+    position: Option<Vec<f64>>,
+    /// Membership weights parallel to `members`, all 1.0 if absent
+    weights: Option<Vec<f64>>,
+}
+
+impl RawParty {
+    /// Membership weights must line up with members and be positive
+    fn check_weights(&self) -> Result<(), String> {
+        let Some(weights) = &self.weights else {
+            return Ok(());
+        };
+        if weights.len() != self.members.len() {
+            return Err(format!(
+                "Party `{}` has {} weights for {} members",
+                self.id,
+                weights.len(),
+                self.members.len()
+            ));
+        }
+        if let Some(w) = weights.iter().find(|w| !(w.is_finite() && **w > 0.0)) {
+            return Err(format!(
+                "Party `{}` has membership weight {}, which must be positive",
+                self.id, w
+            ));
+        }
+        Ok(())
+    }
+
+    /// The party position, checked against the ideal dimension
+    fn checked_position(&self, dim: usize) -> Result<Option<Vec<f64>>, String> {
+        let Some(position) = &self.position else {
+            return Ok(None);
+        };
+        if position.len() != dim {
+            return Err(format!(
+                "Party `{}` position has length {}, but ideal_dimension = {}",
+                self.id,
+                position.len(),
+                dim
+            ));
+        }
+        if let Some(v) = position.iter().find(|v| !v.is_finite()) {
+            return Err(format!(
+                "Party `{}` has non-finite position component {}",
+                self.id, v
+            ));
+        }
+        Ok(Some(position.clone()))
+    }
 }
 
 #[derive(Deserialize)]
@@ -37,37 +389,303 @@ struct RawEdge {
     from: String,
     to: String,
     weight: f64,
+    /// Only influence on proposals touching these dimensions
+    active_dimensions: Option<Vec<usize>>,
+}
+
+/// Load a proposal vector from a file containing either one float per line
+/// or a single TOML/JSON style array such as `[0.5, -1.0, 0.2]`.
+pub fn load_proposal_from_file<P: AsRef<Path>>(
+    path: P,
+) -> Result<DVector<f64>, Box<dyn std::error::Error>> {
+    let content = fs::read_to_string(path)?;
+    let trimmed = content.trim();
+
+    let entries: Vec<&str> = match trimmed
+        .strip_prefix('[')
+        .and_then(|rest| rest.strip_suffix(']'))
+    {
+        Some(inner) => inner.split(',').collect(),
+        None => trimmed.lines().collect(),
+    };
+
+    let mut values = Vec::with_capacity(entries.len());
+    for entry in entries.iter().map(|e| e.trim()).filter(|e| !e.is_empty()) {
+        let value: f64 = entry
+            .parse()
+            .map_err(|_| format!("Invalid proposal value `{}`", entry))?;
+        values.push(value);
+    }
+
+    Ok(DVector::from_vec(values))
+}
+
+/// Errors and warnings found while validating a config without simulating it.
+#[derive(Debug, Default)]
+pub struct ValidationReport {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+impl ValidationReport {
+    /// True if no errors were found (warnings are allowed)
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Run every config check and collect all problems instead of stopping at the
+/// first one: dimensions, value ranges, unknown references, duplicate IDs,
+/// self-loops, duplicate party membership and graph connectivity.
+pub fn validate_config<P: AsRef<Path>>(path: P) -> ValidationReport {
+    let mut report = ValidationReport::default();
+    let path = path.as_ref();
+    let parsed = fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|s| toml::from_str::<RawConfig>(&s).map_err(|e| e.to_string()))
+        .and_then(|mut raw| {
+            resolve_ideal_refs(&mut raw, path.parent().unwrap_or(Path::new("")))?;
+            Ok(raw)
+        });
+    let mut raw = match parsed {
+        Ok(raw) => raw,
+        Err(e) => {
+            report.errors.push(e);
+            return report;
+        }
+    };
+
+    if let Err(e) = migrate_config(&mut raw) {
+        report.errors.push(e);
+        return report;
+    }
+
+    // Members
+    let mut known = HashSet::new();
+    for rm in &raw.congress_members {
+        if !known.insert(rm.id.as_str()) {
+            report
+                .errors
+                .push(format!("Duplicate member id `{}`", rm.id));
+        }
+        if let Err(e) = rm.dense_ideal(raw.ideal_dimension) {
+            report.errors.push(e);
+        }
+        if let Err(e) = rm.check_finite() {
+            report.errors.push(e);
+        }
+        if !(0.0..=1.0).contains(&rm.swing) {
+            report.errors.push(format!(
+                "Member `{}` has swing {} outside [0, 1]",
+                rm.id, rm.swing
+            ));
+        }
+    }
+
+    // Edges
+    for e in raw.edges.iter().flatten() {
+        for endpoint in [&e.from, &e.to] {
+            if !known.contains(endpoint.as_str()) {
+                report.errors.push(format!(
+                    "Edge `{}` -> `{}` refers to unknown member `{}`",
+                    e.from, e.to, endpoint
+                ));
+            }
+        }
+        if let Err(err) = check_edge_weight(e) {
+            report.errors.push(err);
+        }
+        if e.from == e.to {
+            report.warnings.push(format!(
+                "Self-loop on `{}` anchors it to its initial stance",
+                e.from
+            ));
+        }
+        if !(0.0..=1.0).contains(&e.weight) {
+            report.warnings.push(format!(
+                "Edge `{}` -> `{}` has weight {} outside [0, 1]",
+                e.from, e.to, e.weight
+            ));
+        }
+    }
+
+    // Parties
+    let mut membership: HashMap<&str, &str> = HashMap::new();
+    for rp in &raw.parties {
+        if !(0.0..=1.0).contains(&rp.discipline) {
+            report.errors.push(format!(
+                "Party `{}` has discipline {} outside [0, 1]",
+                rp.id, rp.discipline
+            ));
+        }
+        if let Err(e) = rp.checked_position(raw.ideal_dimension) {
+            report.errors.push(e);
+        }
+        if let Err(e) = rp.check_weights() {
+            report.errors.push(e);
+        }
+        for mem_id in &rp.members {
+            if !known.contains(mem_id.as_str()) {
+                report.errors.push(format!(
+                    "Party `{}` refers to unknown member `{}`",
+                    rp.id, mem_id
+                ));
+            } else if let Some(previous) = membership.insert(mem_id, &rp.id) {
+                report.warnings.push(format!(
+                    "Member `{}` belongs to both `{}` and `{}`; their party pressure is blended by membership weight",
+                    mem_id, previous, rp.id
+                ));
+            }
+        }
+    }
+
+    // Vote blocks
+    for (i, block) in raw.vote_blocks.iter().flatten().enumerate() {
+        for mem_id in block {
+            if !known.contains(mem_id.as_str()) {
+                report.errors.push(format!(
+                    "Vote block {} refers to unknown member `{}`",
+                    i, mem_id
+                ));
+            }
+        }
+    }
+
+    // Connectivity, only meaningful once the graph builds
+    if report.is_ok() {
+        match build_congress_graph(raw, &LoaderOptions::default()) {
+            Ok((cg, _)) => {
+                let components = connected_components(&cg.graph);
+                if components > 1 {
+                    report.warnings.push(format!(
+                        "Influence graph has {} disconnected components",
+                        components
+                    ));
+                }
+            }
+            Err(e) => report.errors.push(e.to_string()),
+        }
+    }
+
+    report
 }
 
 /// Load and build a `CongressGraph` from a TOML file.
 pub fn load_congress_graph_from_toml<P: AsRef<Path>>(
     path: P,
 ) -> Result<CongressGraph, Box<dyn std::error::Error>> {
+    let (cg, _) = load_congress_graph_from_toml_with_options(path, &LoaderOptions::default())?;
+    Ok(cg)
+}
+
+/// Load and build a `CongressGraph` from TOML text, e.g. a config that did
+/// not come from disk. A relative `ideals_csv` resolves against the current
+/// directory.
+pub fn load_congress_graph_from_str(
+    toml_str: &str,
+) -> Result<CongressGraph, Box<dyn std::error::Error>> {
+    let mut raw: RawConfig = toml::from_str(toml_str)?;
+    resolve_ideal_refs(&mut raw, Path::new(""))?;
+    let (cg, _) = build_congress_graph(raw, &LoaderOptions::default())?;
+    Ok(cg)
+}
+
+/// Load and build a `CongressGraph` from a TOML file using the given options.
+/// Returns the graph together with any warnings collected in non-strict mode.
+pub fn load_congress_graph_from_toml_with_options<P: AsRef<Path>>(
+    path: P,
+    options: &LoaderOptions,
+) -> Result<(CongressGraph, Vec<String>), Box<dyn std::error::Error>> {
     // 1) Read & parse the TOML
+    let path = path.as_ref();
+    let toml_str = fs::read_to_string(path)?;
+    let mut raw: RawConfig = toml::from_str(&toml_str)?;
+    resolve_ideal_refs(&mut raw, path.parent().unwrap_or(Path::new("")))?;
+    build_congress_graph(raw, options)
+}
+
+/// Load a `CongressGraph` from a TOML file plus a separate CSV edge list of
+/// `from,to,weight` rows (an optional header line is skipped). CSV edges are
+/// added after any inline `edges` and validated the same way.
+pub fn load_congress_graph_with_edges_csv<P: AsRef<Path>, Q: AsRef<Path>>(
+    path: P,
+    edges_path: Q,
+) -> Result<CongressGraph, Box<dyn std::error::Error>> {
+    let path = path.as_ref();
     let toml_str = fs::read_to_string(path)?;
-    let raw: RawConfig = toml::from_str(&toml_str)?;
+    let mut raw: RawConfig = toml::from_str(&toml_str)?;
+    resolve_ideal_refs(&mut raw, path.parent().unwrap_or(Path::new("")))?;
+    let csv_edges = read_edges_csv(edges_path.as_ref())?;
+    raw.edges.get_or_insert_with(Vec::new).extend(csv_edges);
+    let (cg, _) = build_congress_graph(raw, &LoaderOptions::default())?;
+    Ok(cg)
+}
+
+/// Reject configs written for a newer loader and bring older ones up to the
+/// current format.
+///
+/// Version 1 is the only format so far: every field added since (e.g.
+/// `credibility`, `vote_blocks`) is optional and defaults to the behavior
+/// configs had before it existed. A future format change that alters the
+/// meaning of existing fields should bump `CONFIG_VERSION` and fill in the
+/// old meaning here for configs declaring an older version.
+fn migrate_config(raw: &mut RawConfig) -> Result<(), String> {
+    let version = raw.version.unwrap_or(1);
+    if version > CONFIG_VERSION {
+        return Err(format!(
+            "Config version {} is newer than the supported version {}; upgrade polisim",
+            version, CONFIG_VERSION
+        ));
+    }
+    raw.version = Some(CONFIG_VERSION);
+    Ok(())
+}
+
+/// Build a `CongressGraph` from a parsed config
+fn build_congress_graph(
+    raw: RawConfig,
+    options: &LoaderOptions,
+) -> Result<(CongressGraph, Vec<String>), Box<dyn std::error::Error>> {
+    let mut warnings = Vec::new();
+    let mut raw = raw;
+    migrate_config(&mut raw)?;
 
     // 2) Create an empty CongressGraph
     let mut cg = CongressGraph::new();
-
-    // 3) Insert all nodes, checking dimension
-    let mut index_map: HashMap<String, NodeIndex> = HashMap::new();
-    for rm in raw.congress_members {
-        if rm.ideal.len() != raw.ideal_dimension {
+    if let Some(kinds) = raw.dimension_kinds {
+        if kinds.len() != raw.ideal_dimension {
             return Err(format!(
-                "Member `{}` has ideal length {}, but ideal_dimension = {}",
-                rm.id,
-                rm.ideal.len(),
+                "dimension_kinds has length {}, but ideal_dimension = {}",
+                kinds.len(),
                 raw.ideal_dimension
             )
             .into());
         }
+        if let Some(w) = raw.binary_weight.filter(|w| !(0.0..=1.0).contains(w)) {
+            return Err(format!("binary_weight {} is outside [0, 1]", w).into());
+        }
+        cg.set_dimension_kinds(kinds, raw.binary_weight);
+    }
+
+    // 3) Insert all nodes, checking dimension
+    let mut index_map = IdResolver::new(options.case_insensitive_refs);
+    for rm in raw.congress_members {
+        let ideal = rm.dense_ideal(raw.ideal_dimension)?;
+        rm.check_finite()?;
+        // swing blends old score and pressure; outside [0, 1] it extrapolates
+        if !(0.0..=1.0).contains(&rm.swing) {
+            return Err(format!("Member `{}` has swing {} outside [0, 1]", rm.id, rm.swing).into());
+        }
 
         let node = Node {
             id: rm.id.clone(),
-            ideal: DVector::from_vec(rm.ideal),
+            ideal: DVector::from_vec(ideal),
             bias: rm.bias,
             swing: rm.swing,
+            credibility: rm.credibility.unwrap_or(1.0),
+            threshold: rm.threshold,
+            conflict_abstain: rm.conflict_abstain,
+            initial_vote: rm.initial_vote,
         };
         let idx = cg.add_node(node);
         index_map.insert(rm.id, idx);
@@ -76,32 +694,185 @@ pub fn load_congress_graph_from_toml<P: AsRef<Path>>(
     // 4) Insert edges if any
     if let Some(edges) = raw.edges {
         for e in edges {
+            check_edge_weight(&e)?;
             let from_idx = index_map
-                .get(&e.from)
+                .resolve(&e.from)?
                 .ok_or_else(|| format!("Unknown edge.from node `{}`", e.from))?;
             let to_idx = index_map
-                .get(&e.to)
+                .resolve(&e.to)?
                 .ok_or_else(|| format!("Unknown edge.to node `{}`", e.to))?;
-            cg.add_edge(*from_idx, *to_idx, e.weight);
+            match e.active_dimensions {
+                Some(dims) => {
+                    if let Some(&d) = dims.iter().find(|&&d| d >= raw.ideal_dimension) {
+                        return Err(format!(
+                            "Edge `{}` -> `{}` activates on dimension {}, but ideal_dimension = {}",
+                            e.from, e.to, d, raw.ideal_dimension
+                        )
+                        .into());
+                    }
+                    cg.add_conditional_edge(from_idx, to_idx, e.weight, dims);
+                }
+                None => cg.add_edge(from_idx, to_idx, e.weight),
+            }
         }
     }
 
     // 5) Insert parties
     for rp in raw.parties {
+        if !(0.0..=1.0).contains(&rp.discipline) {
+            return Err(format!(
+                "Party `{}` has discipline {} outside [0, 1]",
+                rp.id, rp.discipline
+            )
+            .into());
+        }
+        let position = rp.checked_position(raw.ideal_dimension)?;
+        rp.check_weights()?;
+        let weights = rp.weights.unwrap_or_else(|| vec![1.0; rp.members.len()]);
         let mut members_idx = Vec::with_capacity(rp.members.len());
-        for mem_id in rp.members {
-            let &ni = index_map.get(&mem_id).ok_or_else(|| {
-                format!("Party `{}` refers to unknown member `{}`", rp.id, mem_id)
-            })?;
-            members_idx.push(ni);
+        let mut weights_idx = Vec::with_capacity(rp.members.len());
+        for (mem_id, weight) in rp.members.into_iter().zip(weights) {
+            match index_map.resolve(&mem_id)? {
+                Some(ni) => {
+                    members_idx.push(ni);
+                    weights_idx.push(weight);
+                }
+                None if options.strict_party_members => {
+                    return Err(
+                        format!("Party `{}` refers to unknown member `{}`", rp.id, mem_id).into(),
+                    );
+                }
+                None => warnings.push(format!(
+                    "Party `{}` refers to unknown member `{}`, skipped",
+                    rp.id, mem_id
+                )),
+            }
         }
         let party = Party {
             id: rp.id,
             discipline: rp.discipline,
             members: members_idx,
+            position: position.map(DVector::from_vec),
         };
-        cg.add_party(party);
+        cg.add_party_with_weights(party, weights_idx);
     }
 
-    Ok(cg)
+    // 6) Insert vote blocks if any
+    if let Some(blocks) = raw.vote_blocks {
+        for (i, block) in blocks.into_iter().enumerate() {
+            let mut members_idx = Vec::with_capacity(block.len());
+            for mem_id in block {
+                let ni = index_map.resolve(&mem_id)?.ok_or_else(|| {
+                    format!("Vote block {} refers to unknown member `{}`", i, mem_id)
+                })?;
+                members_idx.push(ni);
+            }
+            cg.add_vote_block(members_idx);
+        }
+    }
+
+    Ok((cg, warnings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A one-dimensional config with the given member tables
+    fn config(members: &str) -> String {
+        format!("ideal_dimension = 1\nparties = []\n{}", members)
+    }
+
+    /// The error message of a config that must fail to load
+    fn load_err(toml_str: &str) -> String {
+        match load_congress_graph_from_str(toml_str) {
+            Ok(_) => panic!("config loaded, but should have been rejected"),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn initial_vote_must_be_a_vote() {
+        let member = |vote: i8| {
+            config(&format!(
+                "[[congress_members]]\nid = \"a\"\nideal = [1.0]\nbias = 0.0\nswing = 0.5\ninitial_vote = {}\n",
+                vote
+            ))
+        };
+
+        let cg = load_congress_graph_from_str(&member(-1)).unwrap();
+        assert_eq!(cg.graph[NodeIndex::new(0)].initial_vote, Some(-1));
+        assert!(load_err(&member(2)).contains("initial_vote 2"));
+    }
+
+    #[test]
+    fn ideal_refs_read_csv_rows() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let cg = load_congress_graph_from_toml(fixtures.join("ideals.toml")).unwrap();
+
+        let ideal = |id| {
+            cg.graph[cg.node_index(id).unwrap()]
+                .ideal
+                .as_slice()
+                .to_vec()
+        };
+        assert_eq!(ideal("from_csv"), vec![-0.25, 0.75]);
+        assert_eq!(ideal("inline"), vec![1.0, 0.0]);
+    }
+
+    #[test]
+    fn ideal_ref_past_last_row_is_rejected() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+        let toml_str = format!(
+            "ideal_dimension = 2\nideals_csv = '{}'\nparties = []\n[[congress_members]]\nid = \"a\"\nideal_ref = 2\nbias = 0.0\nswing = 0.5\n",
+            fixtures.join("ideals.csv").display()
+        );
+        assert!(load_err(&toml_str).contains("has 2 rows"));
+    }
+
+    #[test]
+    fn non_finite_numbers_are_rejected() {
+        let nan_bias =
+            config("[[congress_members]]\nid = \"a\"\nideal = [1.0]\nbias = nan\nswing = 0.5\n");
+        assert!(load_err(&nan_bias).contains("Member `a` has non-finite bias NaN"));
+
+        let inf_edge = config(
+            "[[congress_members]]\nid = \"a\"\nideal = [1.0]\nbias = 0.0\nswing = 0.5\n\
+             [[edges]]\nfrom = \"a\"\nto = \"a\"\nweight = inf\n",
+        );
+        assert!(load_err(&inf_edge).contains("non-finite weight inf"));
+    }
+
+    #[test]
+    fn swing_and_discipline_must_be_in_unit_interval() {
+        let wild_swing =
+            config("[[congress_members]]\nid = \"a\"\nideal = [1.0]\nbias = 0.0\nswing = 1.5\n");
+        assert!(load_err(&wild_swing).contains("swing 1.5 outside [0, 1]"));
+
+        let negative_discipline = "ideal_dimension = 1\n\
+            [[congress_members]]\nid = \"a\"\nideal = [1.0]\nbias = 0.0\nswing = 0.5\n\
+            [[parties]]\nid = \"p\"\ndiscipline = -0.2\nmembers = [\"a\"]\n";
+        assert!(load_err(negative_discipline).contains("discipline -0.2 outside [0, 1]"));
+    }
+
+    #[test]
+    fn undecided_member_is_a_pure_follower() {
+        let undecided = |bias: f64| {
+            config(&format!(
+                "[[congress_members]]\nid = \"leader\"\nideal = [1.0]\nbias = 0.0\nswing = 0.0\n\
+                 [[congress_members]]\nid = \"follower\"\nideal = []\nbias = {}\nswing = 1.0\n\
+                 [[edges]]\nfrom = \"leader\"\nto = \"follower\"\nweight = 1.0\n",
+                bias
+            ))
+        };
+        assert!(load_err(&undecided(0.3)).contains("Member `follower` is undecided"));
+
+        let cg = load_congress_graph_from_str(&undecided(0.0)).unwrap();
+        let follower = cg.node_index("follower").unwrap();
+        let mut sim = crate::sim::Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+        assert_eq!(sim.get_score(follower), 0.0);
+        sim.run_ordered(5, 0.1);
+        assert!(sim.get_score(follower) > 0.0);
+        assert_eq!(sim.get_vote(follower), 1);
+    }
 }
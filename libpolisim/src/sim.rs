@@ -1,33 +1,88 @@
-use nalgebra::DVector;
-use petgraph::graph::{DiGraph, NodeIndex};
-use petgraph::visit::EdgeRef;
+use nalgebra::{DMatrix, DVector};
+use petgraph::algo::{all_simple_paths, tarjan_scc};
+use petgraph::graph::{DiGraph, EdgeIndex, EdgeReference, NodeIndex};
+use petgraph::visit::{Bfs, EdgeRef, IntoNodeReferences};
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::{Rng, thread_rng};
-use std::collections::HashMap;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::hash::RandomState;
 
 // Node attributes representing a congress member
+#[derive(Debug, Clone)]
 pub struct Node {
     pub id: String,
+    /// Ideal point in policy space; all zeros for an undecided member, who
+    /// is neutral on every proposal and simply follows their influencers
     pub ideal: DVector<f64>,
     pub bias: f64,
+    /// Share of each update taken from social pressure, in [0, 1]
     pub swing: f64,
+    /// Multiplies the weight of every edge leaving this member (1.0 = neutral)
+    pub credibility: f64,
+    /// Personal decision threshold overriding the run's threshold when
+    /// votes are finalized (None = use the run's threshold)
+    pub threshold: Option<f64>,
+    /// Abstain at finalization instead of defying the party: if the member's
+    /// own alignment with the proposal opposes the direction of their party
+    /// pressure by more than this tolerance, their vote becomes 0
+    pub conflict_abstain: Option<f64>,
+    /// Position declared before deliberation (-1, 0 or 1), e.g. from a prior
+    /// whip count. Replaces alignment and bias as the member's starting
+    /// score with `DECLARED_VOTE_SCORE` times this sign; 0 starts the member
+    /// at exactly 0.0, neutral and exerting no opinion until they move.
+    pub initial_vote: Option<i8>,
 }
 
+/// Magnitude of the starting score of a member with a declared
+/// `initial_vote`: ten times perfect alignment, so a declared position
+/// outweighs any alignment and bias and takes several rounds of contrary
+/// pressure to wear down
+pub const DECLARED_VOTE_SCORE: f64 = 10.0;
+
 // Party structure with members and discipline factor
 pub struct Party {
     pub id: String,
+    /// Strength of the pull toward the party line, in [0, 1]
     pub discipline: f64,
     pub members: Vec<NodeIndex>,
+    /// Official party stance on policy. When set, the whip pulls members
+    /// toward the sign of its alignment with the proposal, a line decided by
+    /// leadership, instead of toward the members' average opinion.
+    pub position: Option<DVector<f64>>,
 }
 
 // Main simulation graph structure
 pub struct CongressGraph {
     pub graph: DiGraph<Node, f64>,
     parties: Vec<Party>,
-    node_party_map: HashMap<NodeIndex, usize>,
+    /// Every (party index, membership weight) a node belongs to
+    node_party_map: HashMap<NodeIndex, Vec<(usize, f64)>>,
+    id_index_map: HashMap<String, NodeIndex>,
+    dimension_kinds: Vec<DimensionKind>,
+    binary_weight: Option<f64>,
+    /// Active dimensions of each conditional edge
+    edge_dimensions: HashMap<EdgeIndex, Vec<usize>>,
+    vote_blocks: Vec<Vec<NodeIndex>>,
+}
+
+/// Minimum absolute proposal value on a dimension for a conditional edge
+/// listing that dimension to be active
+pub const EDGE_ACTIVATION_THRESHOLD: f64 = 0.05;
+
+/// Kind of an issue dimension, deciding how alignment is measured on it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DimensionKind {
+    /// Compared with cosine similarity
+    Continuous,
+    /// Yes/no policy, compared by sign agreement
+    Binary,
 }
 
 /// Common types of passing threshold
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Majority {
     /// yes > 50%, abstentions do not count
     SIMPLE,
@@ -39,6 +94,364 @@ pub enum Majority {
     ABSSUPER,
     /// 100% yes required(abstention will block)
     UNANIMITY,
+    /// yes > `fraction`, each abstention counting as `abstain_weight` of a
+    /// vote in the denominator: 0.0 ignores abstentions like `SUPER`, 1.0
+    /// counts them fully against like `ABSSUPER`
+    WeightedAbsSuper { fraction: f64, abstain_weight: f64 },
+    /// yes > `fraction` (e.g. 0.6 or 0.75), of all votes if
+    /// `count_abstentions`, otherwise of votes cast
+    Custom {
+        fraction: f64,
+        count_abstentions: bool,
+    },
+}
+
+/// How a vote landing exactly on the passing fraction is decided
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TieBreak {
+    /// The proposal fails, as with plain `passes`
+    Fail,
+    /// The proposal passes
+    Pass,
+    /// The proposal passes if and only if this member (by ID) voted yes
+    PresidingMember(String),
+}
+
+/// Tolerance for treating a yes fraction as exactly on a rule's threshold
+const TIE_TOLERANCE: f64 = 1e-9;
+
+/// How final scores are turned into votes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DecisionRule {
+    /// yes above t, no below -t, abstain in between (used by `run`);
+    /// vote blocks are applied on top
+    HardThreshold { t: f64 },
+    /// yes with probability sigmoid(score / temperature), otherwise no;
+    /// temperature must be positive. Vote blocks are ignored.
+    Logistic { temperature: f64 },
+    /// abstain with probability sigmoid(steepness * (t - |score|)),
+    /// otherwise vote the sign of the score. Low-conviction members abstain
+    /// most; higher steepness sharpens the cutoff at |score| = t.
+    /// Vote blocks are ignored.
+    SoftAbstain { t: f64, steepness: f64 },
+}
+
+/// How the opinions of incoming influencers combine into peer pressure
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PeerAggregation {
+    /// Edge-weighted mean of source opinions (default)
+    #[default]
+    WeightedMean,
+    /// Opinion of the single source with the heaviest edge; the loudest
+    /// influencer dominates regardless of how many others disagree
+    Max,
+    /// Edge-weighted median of source opinions; the typical influencer matters
+    Median,
+    /// Edge-weighted mean of the sources' raw scores instead of their
+    /// opinions, with every update anchored to the member's initial score,
+    /// `(1 - swing) * initial + swing * pressure`, rather than their current
+    /// one. This is the linear influence model whose fixed point
+    /// `Simulator::solve_linear_equilibrium` computes directly; pushes from
+    /// `apply_external_pressure` last only until the member's next update.
+    Linear,
+}
+
+/// How a member's initial score combines policy alignment and bias
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ScoreInit {
+    /// alignment + bias (default)
+    #[default]
+    AddBias,
+    /// alignment * (1 + bias): bias scales conviction instead of shifting it
+    MulBias,
+    /// alignment alone, ignoring bias
+    AlignmentOnly,
+}
+
+/// How `Simulator::run_until_converged` stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvergenceStatus {
+    /// The largest score change in a round fell below the tolerance
+    Converged { rounds: usize },
+    /// The round limit was hit while scores were still settling
+    MaxRoundsReached,
+    /// Score changes stopped shrinking for `OSCILLATION_WINDOW` rounds in a
+    /// row, so scores are cycling rather than settling
+    Oscillating { detected_round: usize },
+}
+
+/// Consecutive rounds without a new smallest score change after which
+/// `Simulator::run_until_converged` reports oscillation
+pub const OSCILLATION_WINDOW: usize = 5;
+
+/// Order in which members are updated within a round
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OrderMode {
+    /// Fresh random order every round (default)
+    #[default]
+    Shuffled,
+    /// `node_indices()` order every round, no randomness involved
+    Fixed,
+    /// Reverse of `node_indices()` order every round
+    ReverseFixed,
+}
+
+/// Counts of each vote kind
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct VoteTally {
+    pub yes: usize,
+    pub no: usize,
+    pub abstain: usize,
+}
+
+impl VoteTally {
+    /// Count votes given as -1, 0 or 1
+    pub fn from_votes<I: IntoIterator<Item = i8>>(votes: I) -> Self {
+        let mut tally = VoteTally::default();
+        for v in votes {
+            match v {
+                1 => tally.yes += 1,
+                -1 => tally.no += 1,
+                0 => tally.abstain += 1,
+                _ => unreachable!("votes should only be -1, 0, or 1"),
+            }
+        }
+        tally
+    }
+
+    /// Votes cast, excluding abstentions
+    pub fn total_cast(&self) -> usize {
+        self.yes + self.no
+    }
+
+    /// All votes, including abstentions
+    pub fn total_all(&self) -> usize {
+        self.yes + self.no + self.abstain
+    }
+
+    /// Yes share under `rule` (of votes cast, or of all votes when
+    /// abstentions count) and the fraction that share must beat; 0.0 share
+    /// when nothing is counted
+    fn yes_share(&self, rule: Majority) -> (f64, f64) {
+        let (cast, all) = (self.total_cast() as f64, self.total_all() as f64);
+        let (counted, required) = match rule {
+            Majority::SIMPLE => (cast, 0.5),
+            Majority::SUPER => (cast, 2.0 / 3.0),
+            Majority::ABSSIMPLE => (all, 0.5),
+            Majority::ABSSUPER => (all, 2.0 / 3.0),
+            Majority::UNANIMITY => (all, 1.0),
+            Majority::WeightedAbsSuper {
+                fraction,
+                abstain_weight,
+            } => (cast + abstain_weight * self.abstain as f64, fraction),
+            Majority::Custom {
+                fraction,
+                count_abstentions,
+            } => (if count_abstentions { all } else { cast }, fraction),
+        };
+        let share = if counted <= 0.0 {
+            0.0
+        } else {
+            self.yes as f64 / counted
+        };
+        (share, required)
+    }
+
+    /// Whether these votes pass under `rule`, with the counts and margin
+    pub fn outcome(&self, rule: Majority) -> VoteOutcome {
+        let (share, required) = self.yes_share(rule);
+        VoteOutcome {
+            passed: self.passes(rule),
+            yes: self.yes,
+            no: self.no,
+            abstain: self.abstain,
+            margin: share - required,
+        }
+    }
+
+    /// Whether these votes pass under `rule`.
+    /// Always false when no votes are counted, whatever the rule.
+    pub fn passes(&self, rule: Majority) -> bool {
+        let (share, required) = self.yes_share(rule);
+        match rule {
+            // yes == total_all
+            Majority::UNANIMITY => self.total_all() > 0 && self.yes == self.total_all(),
+            _ => share > required,
+        }
+    }
+}
+
+/// A vote's result together with how close it was
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct VoteOutcome {
+    pub passed: bool,
+    pub yes: usize,
+    pub no: usize,
+    pub abstain: usize,
+    /// Yes share minus the fraction the rule requires, e.g. 0.1 for 60% yes
+    /// under a simple majority. Passing needs a strictly positive margin,
+    /// except under unanimity where the best possible margin is 0.0.
+    pub margin: f64,
+}
+
+/// Errors raised when a simulation cannot be set up meaningfully
+#[derive(Debug, Clone, PartialEq)]
+pub enum SimError {
+    /// The congress has no members
+    EmptyGraph,
+    /// A member ID that is not in the congress
+    UnknownMember(String),
+    /// A party ID that is not in the congress
+    UnknownParty(String),
+    /// A member's ideal and the proposal have different lengths
+    DimensionMismatch {
+        member: String,
+        ideal: usize,
+        proposal: usize,
+    },
+    /// A member's swing lies outside [0, 1]
+    SwingOutOfRange { member: String, swing: f64 },
+}
+
+impl std::fmt::Display for SimError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SimError::EmptyGraph => write!(f, "congress graph has no members"),
+            SimError::UnknownMember(id) => write!(f, "unknown member `{}`", id),
+            SimError::UnknownParty(id) => write!(f, "unknown party `{}`", id),
+            SimError::DimensionMismatch {
+                member,
+                ideal,
+                proposal,
+            } => write!(
+                f,
+                "member `{}` has an ideal of length {}, but the proposal has length {}",
+                member, ideal, proposal
+            ),
+            SimError::SwingOutOfRange { member, swing } => {
+                write!(f, "member `{}` has swing {} outside [0, 1]", member, swing)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SimError {}
+
+/// Outcome of a single simulation, keyed by member ID
+#[derive(Debug, Clone, Serialize)]
+pub struct SimulationResult {
+    pub proposal: Vec<f64>,
+    pub votes: BTreeMap<String, i8>,
+    pub scores: BTreeMap<String, f64>,
+    pub tally: VoteTally,
+    pub passed: bool,
+    /// See `Simulator::social_welfare`
+    pub social_welfare: f64,
+    /// `Simulator::polarization` between the two largest parties, if there
+    /// are at least two non-empty parties
+    pub polarization: Option<f64>,
+}
+
+/// Size and shape summary of a loaded congress
+#[derive(Debug, Clone, PartialEq)]
+pub struct GraphStats {
+    pub members: usize,
+    pub edges: usize,
+    pub parties: usize,
+    /// Members that belong to no party
+    pub independents: usize,
+    pub avg_in_degree: f64,
+    pub avg_out_degree: f64,
+    /// None for an empty graph
+    pub ideal_dimension: Option<usize>,
+}
+
+impl std::fmt::Display for GraphStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "members:         {}", self.members)?;
+        writeln!(f, "edges:           {}", self.edges)?;
+        writeln!(f, "parties:         {}", self.parties)?;
+        writeln!(f, "independents:    {}", self.independents)?;
+        writeln!(f, "avg in-degree:   {:.2}", self.avg_in_degree)?;
+        writeln!(f, "avg out-degree:  {:.2}", self.avg_out_degree)?;
+        match self.ideal_dimension {
+            Some(dim) => write!(f, "ideal dimension: {}", dim),
+            None => write!(f, "ideal dimension: n/a"),
+        }
+    }
+}
+
+/// Differences between two congress graphs, matched by member ID.
+/// Every list is sorted by member ID.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GraphDiff {
+    pub added_members: Vec<String>,
+    pub removed_members: Vec<String>,
+    /// (member ID, description of one changed attribute)
+    pub changed_members: Vec<(String, String)>,
+    /// (from, to, weight)
+    pub added_edges: Vec<(String, String, f64)>,
+    /// (from, to, weight)
+    pub removed_edges: Vec<(String, String, f64)>,
+    /// (from, to, old weight, new weight)
+    pub reweighted_edges: Vec<(String, String, f64, f64)>,
+    /// (member ID, old party, new party) for members present in both graphs
+    pub party_changes: Vec<(String, Option<String>, Option<String>)>,
+}
+
+impl GraphDiff {
+    /// True if the graphs are equivalent
+    pub fn is_empty(&self) -> bool {
+        self.added_members.is_empty()
+            && self.removed_members.is_empty()
+            && self.changed_members.is_empty()
+            && self.added_edges.is_empty()
+            && self.removed_edges.is_empty()
+            && self.reweighted_edges.is_empty()
+            && self.party_changes.is_empty()
+    }
+}
+
+impl std::fmt::Display for GraphDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "no differences");
+        }
+        for id in &self.added_members {
+            writeln!(f, "+ member {}", id)?;
+        }
+        for id in &self.removed_members {
+            writeln!(f, "- member {}", id)?;
+        }
+        for (id, change) in &self.changed_members {
+            writeln!(f, "~ member {}: {}", id, change)?;
+        }
+        for (from, to, weight) in &self.added_edges {
+            writeln!(f, "+ edge {} -> {} ({})", from, to, weight)?;
+        }
+        for (from, to, weight) in &self.removed_edges {
+            writeln!(f, "- edge {} -> {} ({})", from, to, weight)?;
+        }
+        for (from, to, old, new) in &self.reweighted_edges {
+            writeln!(f, "~ edge {} -> {}: {} -> {}", from, to, old, new)?;
+        }
+        for (id, old, new) in &self.party_changes {
+            writeln!(
+                f,
+                "~ party of {}: {} -> {}",
+                id,
+                old.as_deref().unwrap_or("none"),
+                new.as_deref().unwrap_or("none")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for CongressGraph {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl CongressGraph {
@@ -48,266 +461,2147 @@ impl CongressGraph {
             graph: DiGraph::new(),
             parties: Vec::new(),
             node_party_map: HashMap::new(),
+            id_index_map: HashMap::new(),
+            dimension_kinds: Vec::new(),
+            binary_weight: None,
+            edge_dimensions: HashMap::new(),
+            vote_blocks: Vec::new(),
+        }
+    }
+
+    /// Declares the kind of each issue dimension. `binary_weight` in [0, 1]
+    /// is the share of alignment given to binary dimensions (the rest goes to
+    /// continuous ones); `None` weights each kind by its number of dimensions.
+    /// An empty `kinds` treats every dimension as continuous.
+    pub fn set_dimension_kinds(&mut self, kinds: Vec<DimensionKind>, binary_weight: Option<f64>) {
+        self.dimension_kinds = kinds;
+        self.binary_weight = binary_weight;
+    }
+
+    /// Policy alignment between an ideal point and a proposal: plain cosine
+    /// similarity, or `mixed_alignment` when dimension kinds are declared
+    pub fn alignment(&self, ideal: &DVector<f64>, proposal: &DVector<f64>) -> f64 {
+        if self.dimension_kinds.is_empty() {
+            cosine_similarity(ideal, proposal)
+        } else {
+            mixed_alignment(ideal, proposal, &self.dimension_kinds, self.binary_weight)
         }
     }
 
     /// Adds a new congress member node to the graph
     pub fn add_node(&mut self, node: Node) -> NodeIndex {
-        self.graph.add_node(node)
+        let id = node.id.clone();
+        let idx = self.graph.add_node(node);
+        self.id_index_map.insert(id, idx);
+        idx
+    }
+
+    /// Looks up a member's node index by ID.
+    /// If several members share an ID, the last one added wins.
+    pub fn node_index(&self, id: &str) -> Option<NodeIndex> {
+        self.id_index_map.get(id).copied()
     }
 
-    /// Adds an influence edge between two nodes
+    /// Iterates over every member with its node index, in index order
+    pub fn members(&self) -> impl Iterator<Item = (NodeIndex, &Node)> {
+        self.graph.node_references()
+    }
+
+    /// Adds an influence edge between two nodes.
+    /// A self-loop (`from == to`) acts as an anchor: its weight pulls the
+    /// member back toward their initial stance instead of their current one.
     pub fn add_edge(&mut self, from: NodeIndex, to: NodeIndex, weight: f64) {
         self.graph.add_edge(from, to, weight);
     }
 
-    /// Adds a party to the graph
+    /// Adds an influence edge that only applies to proposals touching the
+    /// given issue dimensions, see `edge_is_active`
+    pub fn add_conditional_edge(
+        &mut self,
+        from: NodeIndex,
+        to: NodeIndex,
+        weight: f64,
+        active_dimensions: Vec<usize>,
+    ) {
+        let edge = self.graph.add_edge(from, to, weight);
+        self.edge_dimensions.insert(edge, active_dimensions);
+    }
+
+    /// Whether an influence edge applies to `proposal`. Unconditional edges
+    /// always apply; conditional ones need the proposal to exceed
+    /// `EDGE_ACTIVATION_THRESHOLD` in absolute value on at least one of
+    /// their dimensions. Each parallel edge between the same two members is
+    /// gated on its own. Edges removed from `graph` directly rather than
+    /// through `remove_edge` leave the conditions of the remaining edges
+    /// out of step.
+    pub fn edge_is_active(&self, edge: EdgeIndex, proposal: &DVector<f64>) -> bool {
+        match self.edge_dimensions.get(&edge) {
+            None => true,
+            Some(dims) => dims.iter().any(|&d| {
+                proposal
+                    .get(d)
+                    .is_some_and(|v| v.abs() > EDGE_ACTIVATION_THRESHOLD)
+            }),
+        }
+    }
+
+    /// Weight of the influence edge between two members given by ID, or
+    /// `None` if either member or the edge does not exist
+    pub fn edge_weight(&self, from: &str, to: &str) -> Option<f64> {
+        let edge = self
+            .graph
+            .find_edge(self.node_index(from)?, self.node_index(to)?)?;
+        Some(self.graph[edge])
+    }
+
+    /// Sets the weight of the influence edge between two members given by
+    /// ID, adding the edge if it does not exist yet
+    pub fn set_edge_weight_by_id(
+        &mut self,
+        from: &str,
+        to: &str,
+        weight: f64,
+    ) -> Result<(), SimError> {
+        let from_idx = self
+            .node_index(from)
+            .ok_or_else(|| SimError::UnknownMember(from.to_string()))?;
+        let to_idx = self
+            .node_index(to)
+            .ok_or_else(|| SimError::UnknownMember(to.to_string()))?;
+        if !self.set_edge_weight(from_idx, to_idx, weight) {
+            self.add_edge(from_idx, to_idx, weight);
+        }
+        Ok(())
+    }
+
+    /// Updates the weight of an existing influence edge.
+    /// Returns false if there is no edge from `from` to `to`.
+    pub fn set_edge_weight(&mut self, from: NodeIndex, to: NodeIndex, weight: f64) -> bool {
+        match self.graph.find_edge(from, to) {
+            Some(edge) => {
+                self.graph[edge] = weight;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes the influence edge between two nodes.
+    /// Returns false if there is no edge from `from` to `to`.
+    pub fn remove_edge(&mut self, from: NodeIndex, to: NodeIndex) -> bool {
+        match self.graph.find_edge(from, to) {
+            Some(edge) => {
+                // petgraph moves the last edge into the removed edge's slot
+                let last = EdgeIndex::new(self.graph.edge_count() - 1);
+                self.graph.remove_edge(edge);
+                self.edge_dimensions.remove(&edge);
+                if let Some(dims) = self.edge_dimensions.remove(&last) {
+                    self.edge_dimensions.insert(edge, dims);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Rescales each node's incoming edge weights to sum to 1.0.
+    /// Nodes whose incoming weights sum to zero are left untouched.
+    pub fn normalize_incoming_weights(&mut self) {
+        for node_idx in self.graph.node_indices() {
+            let incoming: Vec<_> = self
+                .graph
+                .edges_directed(node_idx, petgraph::Direction::Incoming)
+                .map(|e| e.id())
+                .collect();
+            let total: f64 = incoming.iter().map(|&e| self.graph[e]).sum();
+            if total.abs() > f64::EPSILON {
+                for e in incoming {
+                    self.graph[e] /= total;
+                }
+            }
+        }
+    }
+
+    /// Rescales each node's outgoing edge weights in place to sum to 1.0, so
+    /// raw relationship strengths become each member's share of influence.
+    /// Nodes with no outgoing edges, or whose weights sum to zero, are left
+    /// untouched.
+    pub fn normalize_out_weights(&mut self) {
+        for node_idx in self.graph.node_indices() {
+            let outgoing: Vec<_> = self
+                .graph
+                .edges_directed(node_idx, petgraph::Direction::Outgoing)
+                .map(|e| e.id())
+                .collect();
+            let total: f64 = outgoing.iter().map(|&e| self.graph[e]).sum();
+            if total.abs() > f64::EPSILON {
+                for e in outgoing {
+                    self.graph[e] /= total;
+                }
+            }
+        }
+    }
+
+    /// Adds a party to the graph, every member with membership weight 1.0
     pub fn add_party(&mut self, party: Party) {
+        let weights = vec![1.0; party.members.len()];
+        self.add_party_with_weights(party, weights);
+    }
+
+    /// Adds a party whose members belong to it with the given positive
+    /// weights, parallel to `party.members`. A member of several parties
+    /// feels each party's pressure blended by membership weight, so a member
+    /// split 50/50 between two opposing parties feels none.
+    pub fn add_party_with_weights(&mut self, party: Party, weights: Vec<f64>) {
+        assert_eq!(
+            party.members.len(),
+            weights.len(),
+            "party `{}` needs one weight per member",
+            party.id
+        );
         let party_idx = self.parties.len();
-        for &member in &party.members {
-            self.node_party_map.insert(member, party_idx);
+        for (&member, weight) in party.members.iter().zip(weights) {
+            self.node_party_map
+                .entry(member)
+                .or_default()
+                .push((party_idx, weight));
         }
         self.parties.push(party);
     }
 
-    /// Retrieves party index for a node
+    /// Retrieves the primary party index for a node: the party with the
+    /// largest membership weight, the last one added on a tie
     pub fn get_party_index(&self, node_idx: NodeIndex) -> Option<usize> {
-        self.node_party_map.get(&node_idx).copied()
+        self.party_memberships(node_idx)
+            .iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|&(party_idx, _)| party_idx)
+    }
+
+    /// Every (party index, membership weight) pair for a node, in the order
+    /// the parties were added; empty for an independent
+    pub fn party_memberships(&self, node_idx: NodeIndex) -> &[(usize, f64)] {
+        self.node_party_map
+            .get(&node_idx)
+            .map_or(&[], Vec::as_slice)
     }
 
     /// Gets party reference by index
     pub fn get_party(&self, party_idx: usize) -> Option<&Party> {
         self.parties.get(party_idx)
     }
-}
 
-// Simulator for running voting simulations
-pub struct Simulator<'a> {
-    congress: &'a CongressGraph,
-    proposal: DVector<f64>,
-    scores: Vec<f64>,
-    votes: Vec<i8>,
-}
+    /// Registers a coalition whose members vote together at finalization,
+    /// based on the block's mean score. If a member is listed in several
+    /// blocks, the last one added decides their vote.
+    pub fn add_vote_block(&mut self, members: Vec<NodeIndex>) {
+        self.vote_blocks.push(members);
+    }
 
-impl<'a> Simulator<'a> {
-    /// Creates a new simulator for a given proposal
-    pub fn new(congress: &'a CongressGraph, proposal: DVector<f64>) -> Self {
-        let node_count = congress.graph.node_count();
-        let mut scores = vec![0.0; node_count];
+    /// All vote blocks in insertion order
+    pub fn vote_blocks(&self) -> &[Vec<NodeIndex>] {
+        &self.vote_blocks
+    }
 
-        // Initialize scores based on policy alignment + personal bias
-        for node_idx in congress.graph.node_indices() {
-            let node = &congress.graph[node_idx];
-            let alignment = cosine_similarity(&node.ideal, &proposal);
-            scores[node_idx.index()] = alignment + node.bias;
-        }
+    /// All parties in insertion order
+    pub fn parties(&self) -> &[Party] {
+        &self.parties
+    }
 
-        Simulator {
-            congress,
-            proposal,
-            scores,
-            votes: vec![0; node_count],
-        }
+    /// Dimension of the members' ideal vectors, or None for an empty graph
+    pub fn ideal_dimension(&self) -> Option<usize> {
+        self.graph.node_weights().next().map(|n| n.ideal.len())
     }
 
-    /// Runs the simulation for specified number of rounds
-    pub fn run(&mut self, max_rounds: usize, threshold: f64) {
-        let mut rng = thread_rng();
-        let node_indices: Vec<NodeIndex> = self.congress.graph.node_indices().collect();
+    /// IDs of every member reachable from `id` by following outgoing
+    /// influence edges, in breadth-first order. The member itself is
+    /// excluded; an unknown ID yields an empty list.
+    pub fn influence_closure(&self, id: &str) -> Vec<String> {
+        let Some(start) = self.node_index(id) else {
+            return Vec::new();
+        };
 
-        for _ in 0..max_rounds {
-            let mut order = node_indices.clone();
-            order.shuffle(&mut rng);
+        let mut reached = Vec::new();
+        let mut bfs = Bfs::new(&self.graph, start);
+        while let Some(node_idx) = bfs.next(&self.graph) {
+            if node_idx != start {
+                reached.push(self.graph[node_idx].id.clone());
+            }
+        }
+        reached
+    }
 
-            for &node_idx in &order {
-                // Calculate peer pressure from influences
-                let peer_pressure = self.calculate_peer_pressure(node_idx);
+    /// Members with no incoming edges (self-loops count) and no party, in
+    /// index order. Nothing in the graph moves them, so their vote is their
+    /// baseline unless an optional simulator pressure (proximity, salience)
+    /// is enabled. Many of these can flag an under-specified config.
+    pub fn unmovable_members(&self) -> Vec<NodeIndex> {
+        self.graph
+            .node_indices()
+            .filter(|&n| {
+                self.get_party_index(n).is_none()
+                    && self
+                        .graph
+                        .edges_directed(n, petgraph::Direction::Incoming)
+                        .next()
+                        .is_none()
+            })
+            .collect()
+    }
 
-                // Calculate party discipline pressure
-                let party_pressure = self.calculate_party_pressure(node_idx);
+    /// Eigenvector centrality of each member as an influencer, keyed by ID:
+    /// a member scores highly when they influence members who are
+    /// themselves influential. Computed by power iteration on `I + W`, where
+    /// `W[i][j]` is the weight of edge `i -> j` (the identity shift keeps
+    /// the iteration from oscillating on cyclic graphs without changing the
+    /// eigenvectors). Stops after `iterations` steps or once no entry moves
+    /// by more than `tolerance`; the result has unit Euclidean norm.
+    ///
+    /// Weights are assumed non-negative. On a graph with several components
+    /// the result concentrates on the component with the strongest feedback
+    /// loops, and members of the others tend toward zero, so compare scores
+    /// within a component (see `influence_sccs`) rather than across them.
+    pub fn eigenvector_centrality(
+        &self,
+        iterations: usize,
+        tolerance: f64,
+    ) -> HashMap<String, f64> {
+        let n = self.graph.node_count();
+        let mut shifted = DMatrix::<f64>::identity(n, n);
+        for edge in self.graph.edge_references() {
+            shifted[(edge.source().index(), edge.target().index())] += *edge.weight();
+        }
 
-                // Update node score
-                self.update_node_score(node_idx, peer_pressure + party_pressure);
+        let mut centrality = DVector::from_element(n, 1.0 / (n.max(1) as f64).sqrt());
+        for _ in 0..iterations {
+            let mut next = &shifted * &centrality;
+            let norm = next.norm();
+            if norm < f64::EPSILON {
+                break;
+            }
+            next /= norm;
+            let change = (&next - &centrality).amax();
+            centrality = next;
+            if change <= tolerance {
+                break;
             }
         }
 
-        // Finalize votes using threshold
-        for node_idx in self.congress.graph.node_indices() {
-            let score = self.scores[node_idx.index()];
-            self.votes[node_idx.index()] = if score > threshold {
-                1
-            } else if score < -threshold {
-                -1
-            } else {
-                0
-            };
-        }
+        self.members()
+            .map(|(idx, member)| (member.id.clone(), centrality[idx.index()]))
+            .collect()
     }
 
-    /// Calculate peer pressure from incoming influences
-    fn calculate_peer_pressure(&self, node_idx: NodeIndex) -> f64 {
-        let mut weighted_sum = 0.0;
-        let mut total_weight = 0.0;
+    /// Every simple directed path from `from` to `to` with at most `max_len`
+    /// edges, each listed as its node sequence including both ends. The
+    /// bound keeps the search tractable on dense graphs. Paths are ordered
+    /// shortest first, ties broken by comparing node indices along the path.
+    /// Empty if `from == to`, as self-loops are anchors rather than paths.
+    pub fn influence_paths(
+        &self,
+        from: NodeIndex,
+        to: NodeIndex,
+        max_len: usize,
+    ) -> Vec<Vec<NodeIndex>> {
+        if max_len == 0 || from == to {
+            return Vec::new();
+        }
+        let mut paths: Vec<Vec<NodeIndex>> =
+            all_simple_paths::<Vec<_>, _, RandomState>(&self.graph, from, to, 0, Some(max_len - 1))
+                .collect();
+        paths.sort_by(|a, b| a.len().cmp(&b.len()).then_with(|| a.cmp(b)));
+        paths
+    }
 
-        for edge in self
-            .congress
-            .graph
-            .edges_directed(node_idx, petgraph::Direction::Incoming)
-        {
-            let source_idx = edge.source();
-            let weight = *edge.weight();
-            let source_score = self.scores[source_idx.index()].signum();
+    /// Strongly connected components of the influence graph, as member IDs.
+    /// Components with more than one member are feedback loops.
+    pub fn influence_sccs(&self) -> Vec<Vec<String>> {
+        tarjan_scc(&self.graph)
+            .into_iter()
+            .map(|component| {
+                component
+                    .into_iter()
+                    .map(|node_idx| self.graph[node_idx].id.clone())
+                    .collect()
+            })
+            .collect()
+    }
 
-            weighted_sum += weight * source_score;
-            total_weight += weight;
+    /// Laakso–Taagepera effective number of parties, `1 / sum(share^2)` over
+    /// each party's fraction of all members. Each independent counts as a
+    /// party of one, so a body of independents has as many effective parties
+    /// as members. A member listed in several parties counts once, for their
+    /// primary party. 0.0 for an empty graph.
+    pub fn effective_num_parties(&self) -> f64 {
+        let total = self.graph.node_count();
+        if total == 0 {
+            return 0.0;
         }
-
-        if total_weight.abs() > f64::EPSILON {
-            weighted_sum / total_weight
-        } else {
-            0.0
+        let mut sizes = vec![0usize; self.parties.len()];
+        let mut independents = 0usize;
+        for node_idx in self.graph.node_indices() {
+            match self.get_party_index(node_idx) {
+                Some(party_idx) => sizes[party_idx] += 1,
+                None => independents += 1,
+            }
         }
+        let share_sq = |size: usize| (size as f64 / total as f64).powi(2);
+        let concentration: f64 =
+            sizes.iter().map(|&s| share_sq(s)).sum::<f64>() + independents as f64 * share_sq(1);
+        1.0 / concentration
     }
 
-    /// Calculate party discipline pressure
-    fn calculate_party_pressure(&self, node_idx: NodeIndex) -> f64 {
-        self.congress
-            .get_party_index(node_idx)
-            .and_then(|party_idx| self.congress.get_party(party_idx))
-            .map(|party| {
-                let mut total_vote = 0.0;
-                let mut count = 0;
-
-                for &member in &party.members {
-                    total_vote += self.scores[member.index()].signum();
-                    count += 1;
-                }
+    /// Counts and average degrees, for a quick check that a config loaded
+    /// as intended
+    pub fn stats(&self) -> GraphStats {
+        let members = self.graph.node_count();
+        let edges = self.graph.edge_count();
+        // Every edge has exactly one source and one target, so the two
+        // averages agree; they are reported separately for readability
+        let avg_degree = if members == 0 {
+            0.0
+        } else {
+            edges as f64 / members as f64
+        };
 
-                // Avoid division by zero for empty parties
-                if count == 0 {
-                    0.0
-                } else {
-                    party.discipline * (total_vote / count as f64)
-                }
-            })
-            .unwrap_or(0.0) // No party affiliation
+        GraphStats {
+            members,
+            edges,
+            parties: self.parties.len(),
+            independents: self
+                .graph
+                .node_indices()
+                .filter(|&n| self.get_party_index(n).is_none())
+                .count(),
+            avg_in_degree: avg_degree,
+            avg_out_degree: avg_degree,
+            ideal_dimension: self.ideal_dimension(),
+        }
     }
 
-    /// Update node score based on social pressure
-    fn update_node_score(&mut self, node_idx: NodeIndex, social_pressure: f64) {
-        let node = &self.congress.graph[node_idx];
-        let swing_factor = node.swing;
-        let current_score = self.scores[node_idx.index()];
+    /// Votes from initial alignment plus bias alone, with no social
+    /// influence: the baseline a zero-round `run` produces, keyed by member
+    /// ID. Personal thresholds and vote blocks still apply.
+    pub fn naive_votes(&self, proposal: &DVector<f64>, threshold: f64) -> HashMap<String, i8> {
+        let mut sim = Simulator::new(self, proposal.clone());
+        sim.run(0, threshold);
+        sim.get_votes()
+    }
 
-        self.scores[node_idx.index()] =
-            (1.0 - swing_factor) * current_score + swing_factor * social_pressure;
+    /// Runs a full simulation in one call and bundles every summary of it.
+    /// The update order is drawn from a RNG seeded with `seed`, so the
+    /// result is reproducible.
+    pub fn simulate(
+        &self,
+        proposal: DVector<f64>,
+        rounds: usize,
+        threshold: f64,
+        rule: Majority,
+        seed: u64,
+    ) -> SimulationResult {
+        let mut sim = Simulator::new(self, proposal);
+        sim.run_with_rng(rounds, threshold, &mut StdRng::seed_from_u64(seed));
+        sim.result(rule)
     }
 
-    /// Get final votes of all nodes,
-    /// return a HashMap with node ID as key
-    pub fn get_votes(&self) -> std::collections::HashMap<String, i8> {
-        let mut map = std::collections::HashMap::new();
-        for node_idx in self.congress.graph.node_indices() {
-            let node = &self.congress.graph[node_idx];
-            let vote = self.votes[node_idx.index()];
-            map.insert(node.id.clone(), vote);
+    /// Extracts the induced subgraph over `member_ids`, e.g. a committee.
+    /// Only edges between included members are kept, and parties and vote
+    /// blocks are restricted to included members (parties left empty are
+    /// dropped). Dimension settings carry over. Fails on an unknown ID;
+    /// repeated IDs are included once.
+    pub fn subgraph(&self, member_ids: &[String]) -> Result<CongressGraph, SimError> {
+        let mut sub = CongressGraph::new();
+        sub.dimension_kinds = self.dimension_kinds.clone();
+        sub.binary_weight = self.binary_weight;
+
+        let mut index_map: HashMap<NodeIndex, NodeIndex> = HashMap::new();
+        for id in member_ids {
+            let old_idx = self
+                .node_index(id)
+                .ok_or_else(|| SimError::UnknownMember(id.clone()))?;
+            index_map
+                .entry(old_idx)
+                .or_insert_with(|| sub.add_node(self.graph[old_idx].clone()));
         }
-        map
-    }
 
-    /// Get the vote result(proposal passes or not)
-    pub fn passes(&self, rule: Majority) -> bool {
-        // Count votes
-        let mut yes = 0usize;
-        let mut no = 0usize;
-        let mut abstain = 0usize;
+        for edge in self.graph.edge_references() {
+            let (Some(&from), Some(&to)) =
+                (index_map.get(&edge.source()), index_map.get(&edge.target()))
+            else {
+                continue;
+            };
+            match self.edge_dimensions.get(&edge.id()) {
+                Some(dims) => sub.add_conditional_edge(from, to, *edge.weight(), dims.clone()),
+                None => sub.add_edge(from, to, *edge.weight()),
+            }
+        }
 
-        for &v in &self.votes {
-            match v {
-                1 => yes += 1,
-                -1 => no += 1,
-                0 => abstain += 1,
-                _ => unreachable!("votes should only be -1, 0, or 1"),
+        let restrict = |members: &[NodeIndex]| -> Vec<NodeIndex> {
+            members
+                .iter()
+                .filter_map(|m| index_map.get(m).copied())
+                .collect()
+        };
+        for (party_idx, party) in self.parties.iter().enumerate() {
+            let weights: Vec<f64> = party
+                .members
+                .iter()
+                .filter(|m| index_map.contains_key(m))
+                .map(|&m| {
+                    self.party_memberships(m)
+                        .iter()
+                        .find(|&&(p, _)| p == party_idx)
+                        .map_or(1.0, |&(_, w)| w)
+                })
+                .collect();
+            let members = restrict(&party.members);
+            if !members.is_empty() {
+                sub.add_party_with_weights(
+                    Party {
+                        id: party.id.clone(),
+                        discipline: party.discipline,
+                        members,
+                        position: party.position.clone(),
+                    },
+                    weights,
+                );
             }
         }
+        for block in &self.vote_blocks {
+            let members = restrict(block);
+            if !members.is_empty() {
+                sub.add_vote_block(members);
+            }
+        }
+        Ok(sub)
+    }
 
-        let total_cast = yes + no; // excludes abstentions
-        let total_all = yes + no + abstain;
+    /// Compares this graph (the old one) with `other` (the new one), matching
+    /// members by ID. Parallel edges between the same pair of members are
+    /// compared by their last weight.
+    pub fn diff(&self, other: &CongressGraph) -> GraphDiff {
+        let members = |cg: &CongressGraph| -> BTreeMap<String, NodeIndex> {
+            cg.members().map(|(idx, n)| (n.id.clone(), idx)).collect()
+        };
+        let edges = |cg: &CongressGraph| -> BTreeMap<(String, String), f64> {
+            cg.graph
+                .edge_references()
+                .map(|e| {
+                    let key = (
+                        cg.graph[e.source()].id.clone(),
+                        cg.graph[e.target()].id.clone(),
+                    );
+                    (key, *e.weight())
+                })
+                .collect()
+        };
+        let party_of = |cg: &CongressGraph, idx: NodeIndex| {
+            cg.get_party_index(idx).map(|p| cg.parties[p].id.clone())
+        };
 
-        match rule {
-            Majority::SIMPLE => {
-                // yes / (yes+no) > 0.5
-                if total_cast == 0 {
-                    false
-                } else {
-                    (yes as f64) / (total_cast as f64) > 0.5
-                }
+        let mut diff = GraphDiff::default();
+        let (old_members, new_members) = (members(self), members(other));
+        for (id, &old_idx) in &old_members {
+            let Some(&new_idx) = new_members.get(id) else {
+                diff.removed_members.push(id.clone());
+                continue;
+            };
+            let (old, new) = (&self.graph[old_idx], &other.graph[new_idx]);
+            let mut changed = |what: String| diff.changed_members.push((id.clone(), what));
+            if old.ideal != new.ideal {
+                changed(format!(
+                    "ideal {:?} -> {:?}",
+                    old.ideal.as_slice(),
+                    new.ideal.as_slice()
+                ));
             }
-            Majority::SUPER => {
-                // yes / (yes+no) > 2/3
-                if total_cast == 0 {
-                    false
-                } else {
-                    (yes as f64) / (total_cast as f64) > (2.0 / 3.0)
-                }
+            if old.bias != new.bias {
+                changed(format!("bias {} -> {}", old.bias, new.bias));
             }
-            Majority::ABSSIMPLE => {
-                // yes / total_all > 0.5
-                if total_all == 0 {
-                    false
-                } else {
-                    (yes as f64) / (total_all as f64) > 0.5
-                }
+            if old.swing != new.swing {
+                changed(format!("swing {} -> {}", old.swing, new.swing));
             }
-            Majority::ABSSUPER => {
-                // yes / total_all > 2/3
-                if total_all == 0 {
-                    false
-                } else {
-                    (yes as f64) / (total_all as f64) > (2.0 / 3.0)
+            if old.threshold != new.threshold {
+                changed(format!(
+                    "threshold {:?} -> {:?}",
+                    old.threshold, new.threshold
+                ));
+            }
+            if old.conflict_abstain != new.conflict_abstain {
+                changed(format!(
+                    "conflict_abstain {:?} -> {:?}",
+                    old.conflict_abstain, new.conflict_abstain
+                ));
+            }
+            if old.credibility != new.credibility {
+                changed(format!(
+                    "credibility {} -> {}",
+                    old.credibility, new.credibility
+                ));
+            }
+            if old.initial_vote != new.initial_vote {
+                changed(format!(
+                    "initial_vote {:?} -> {:?}",
+                    old.initial_vote, new.initial_vote
+                ));
+            }
+            let (old_party, new_party) = (party_of(self, old_idx), party_of(other, new_idx));
+            if old_party != new_party {
+                diff.party_changes.push((id.clone(), old_party, new_party));
+            }
+        }
+        diff.added_members = new_members
+            .keys()
+            .filter(|id| !old_members.contains_key(*id))
+            .cloned()
+            .collect();
+
+        let (old_edges, new_edges) = (edges(self), edges(other));
+        for ((from, to), &old_weight) in &old_edges {
+            match new_edges.get(&(from.clone(), to.clone())) {
+                None => diff
+                    .removed_edges
+                    .push((from.clone(), to.clone(), old_weight)),
+                Some(&new_weight) if new_weight != old_weight => {
+                    diff.reweighted_edges
+                        .push((from.clone(), to.clone(), old_weight, new_weight))
                 }
+                Some(_) => {}
             }
-            Majority::UNANIMITY => {
-                // yes == total_all
-                total_all > 0 && yes == total_all
+        }
+        for ((from, to), &new_weight) in &new_edges {
+            if !old_edges.contains_key(&(from.clone(), to.clone())) {
+                diff.added_edges
+                    .push((from.clone(), to.clone(), new_weight));
             }
         }
+        diff
     }
 
-    /// Get final vote of a node
-    pub fn get_vote(&self, node_idx: NodeIndex) -> i8 {
-        self.votes[node_idx.index()]
+    /// Renders the graph in Graphviz DOT format, one cluster per party
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph Congress {\n");
+        for (i, party) in self.parties.iter().enumerate() {
+            out.push_str(&format!("  subgraph cluster_{} {{\n", i));
+            out.push_str(&format!(
+                "    label=\"{} (discipline = {})\";\n",
+                party.id, party.discipline
+            ));
+            for &member in &party.members {
+                out.push_str(&format!("    \"{}\";\n", self.graph[member].id));
+            }
+            out.push_str("  }\n");
+        }
+        for node_idx in self.graph.node_indices() {
+            if self.get_party_index(node_idx).is_none() {
+                out.push_str(&format!("  \"{}\";\n", self.graph[node_idx].id));
+            }
+        }
+        for edge in self.graph.edge_references() {
+            out.push_str(&format!(
+                "  \"{}\" -> \"{}\" [label=\"{}\"];\n",
+                self.graph[edge.source()].id,
+                self.graph[edge.target()].id,
+                edge.weight()
+            ));
+        }
+        out.push_str("}\n");
+        out
     }
+}
 
-    /// Get current score of a node
-    pub fn get_score(&self, node_idx: NodeIndex) -> f64 {
-        self.scores[node_idx.index()]
-    }
+// Simulator for running voting simulations
+#[derive(Clone)]
+pub struct Simulator<'a> {
+    congress: &'a CongressGraph,
+    proposal: DVector<f64>,
+    initial_scores: Vec<f64>,
+    scores: Vec<f64>,
+    votes: Vec<i8>,
+    cumulative_pressure: Vec<f64>,
+    freeze_party_average: bool,
+    peer_aggregation: PeerAggregation,
+    history: Option<Vec<Vec<f64>>>,
+    clamp: Option<(f64, f64)>,
+    proximity: Option<(f64, DMatrix<f64>)>,
+    order_mode: OrderMode,
+    salience: Option<(f64, Vec<DVector<f64>>)>,
+    rounds_done: usize,
+    ema_alpha: f64,
+    smoothed_pressure: Vec<f64>,
+    copartisan_boost: f64,
+    /// Members edited through `update_member`, shadowing the graph's nodes
+    edited: HashMap<NodeIndex, Node>,
+    status_quo: Option<DVector<f64>>,
+    score_init: ScoreInit,
+    /// Votes forced at finalization, and the pinned members whose score is
+    /// also held at their vote
+    pins: HashMap<NodeIndex, i8>,
+    locked_scores: HashSet<NodeIndex>,
+    /// Party discipline values replacing the congress's, by party index
+    discipline_overrides: HashMap<usize, f64>,
 }
 
-/// Computes cosine similarity between two vectors
-pub fn cosine_similarity(a: &DVector<f64>, b: &DVector<f64>) -> f64 {
-    let dot_product = a.dot(b);
-    let norm_a = a.norm();
-    let norm_b = b.norm();
+impl<'a> Simulator<'a> {
+    /// Creates a new simulator, rejecting inputs that would give meaningless
+    /// results instead of accepting or panicking on them like `new`: an
+    /// empty congress, or a proposal whose length differs from a member's
+    /// ideal
+    pub fn try_new(congress: &'a CongressGraph, proposal: DVector<f64>) -> Result<Self, SimError> {
+        if congress.graph.node_count() == 0 {
+            return Err(SimError::EmptyGraph);
+        }
+        check_dimensions(congress, &proposal)?;
+        Ok(Self::new(congress, proposal))
+    }
 
-    if norm_a.abs() < f64::EPSILON || norm_b.abs() < f64::EPSILON {
-        0.0
-    } else {
-        dot_product / (norm_a * norm_b)
+    /// Creates a simulator where members judge the proposal against
+    /// `status_quo` instead of the origin: alignment is
+    /// `status_quo_alignment`, so a member supports a proposal that moves
+    /// policy closer to their ideal than the status quo, whatever its
+    /// direction from the origin.
+    pub fn new_with_status_quo(
+        congress: &'a CongressGraph,
+        proposal: DVector<f64>,
+        status_quo: DVector<f64>,
+    ) -> Self {
+        let mut sim = Self::new(congress, proposal);
+        sim.status_quo = Some(status_quo);
+        sim.reset_baselines();
+        sim
     }
-}
+
+    /// Creates a simulator whose initial scores combine alignment and bias
+    /// as `init` says; `new` uses `ScoreInit::AddBias`. The same formula
+    /// applies whenever a baseline is recomputed, e.g. by `amend_proposal`.
+    pub fn with_score_init(
+        congress: &'a CongressGraph,
+        proposal: DVector<f64>,
+        init: ScoreInit,
+    ) -> Self {
+        let mut sim = Self::new(congress, proposal);
+        sim.score_init = init;
+        sim.reset_baselines();
+        sim
+    }
+
+    /// Recompute every member's initial and current score from scratch
+    fn reset_baselines(&mut self) {
+        for node_idx in self.congress.graph.node_indices() {
+            let baseline = self.baseline(self.member(node_idx), &self.proposal);
+            self.initial_scores[node_idx.index()] = baseline;
+            self.scores[node_idx.index()] = baseline;
+        }
+    }
+
+    /// Creates a new simulator for a given proposal.
+    /// An empty congress is accepted: `run` is a no-op and `passes` is false.
+    /// Panics if the proposal's length differs from a member's ideal; use
+    /// `try_new` to get an error instead.
+    pub fn new(congress: &'a CongressGraph, proposal: DVector<f64>) -> Self {
+        if let Err(e) = check_dimensions(congress, &proposal) {
+            panic!("cannot simulate: {}", e);
+        }
+        let node_count = congress.graph.node_count();
+
+        let mut sim = Simulator {
+            congress,
+            proposal,
+            initial_scores: vec![0.0; node_count],
+            scores: vec![0.0; node_count],
+            votes: vec![0; node_count],
+            cumulative_pressure: vec![0.0; node_count],
+            freeze_party_average: false,
+            peer_aggregation: PeerAggregation::WeightedMean,
+            history: None,
+            clamp: None,
+            proximity: None,
+            order_mode: OrderMode::Shuffled,
+            salience: None,
+            rounds_done: 0,
+            ema_alpha: 1.0,
+            smoothed_pressure: vec![0.0; node_count],
+            copartisan_boost: 1.0,
+            edited: HashMap::new(),
+            status_quo: None,
+            score_init: ScoreInit::AddBias,
+            pins: HashMap::new(),
+            locked_scores: HashSet::new(),
+            discipline_overrides: HashMap::new(),
+        };
+        // Initialize scores based on policy alignment + personal bias
+        sim.reset_baselines();
+        sim
+    }
+
+    /// Choose the member update order within each round. The fixed modes
+    /// give reproducible runs without a seed and make order-dependence
+    /// itself easy to study.
+    pub fn set_order_mode(&mut self, mode: OrderMode) {
+        self.order_mode = mode;
+    }
+
+    /// Let ideology create implicit edges: each round a member also feels
+    /// `coefficient` times the mean, over every other member, of their ideal
+    /// cosine similarity times that member's current opinion (sign). Similar
+    /// members pull together, opposed ones push apart. This costs O(n^2)
+    /// memory and time per round, so it is off by default; a coefficient of
+    /// 0.0 disables it again.
+    pub fn set_proximity_influence(&mut self, coefficient: f64) {
+        if coefficient == 0.0 {
+            self.proximity = None;
+            return;
+        }
+        let n = self.congress.graph.node_count();
+        let similarity = DMatrix::from_fn(n, n, |i, j| {
+            if i == j {
+                0.0
+            } else {
+                let a = &self.member(NodeIndex::new(i)).ideal;
+                let b = &self.member(NodeIndex::new(j)).ideal;
+                cosine_similarity(a, b)
+            }
+        });
+        self.proximity = Some((coefficient, similarity));
+    }
+
+    /// Re-inject each member's own view of the proposal every round, with
+    /// issue salience that shifts over the debate. In round `r` (counted
+    /// from 0 across `run` and `step` calls) a member also feels `weight`
+    /// times their salience-weighted alignment (see
+    /// `weighted_cosine_similarity`) plus bias, using `schedule[r]` as the
+    /// per-dimension weights. The schedule should hold one entry per round,
+    /// i.e. at least `max_rounds` entries; later rounds reuse the last entry.
+    /// Every entry must have the ideal dimension. An empty schedule or a
+    /// weight of 0.0 disables re-injection (the default).
+    pub fn set_salience_schedule(&mut self, schedule: Vec<DVector<f64>>, weight: f64) {
+        self.salience = (weight != 0.0 && !schedule.is_empty()).then_some((weight, schedule));
+    }
+
+    /// Clamp every score into `[min, max]` after each update. `None` (the
+    /// default) leaves scores unbounded; bounding them keeps logistic
+    /// decisions and normalized scores meaningful when pressure compounds.
+    /// `min` must not exceed `max`.
+    pub fn set_clamp(&mut self, clamp: Option<(f64, f64)>) {
+        self.clamp = clamp;
+    }
+
+    /// Record the scores after every round, see `history`
+    pub fn set_track_history(&mut self, track: bool) {
+        self.history = track.then(Vec::new);
+    }
+
+    /// Scores after each round so far (indexed by node index), if tracking
+    /// was enabled with `set_track_history`
+    pub fn history(&self) -> Option<&[Vec<f64>]> {
+        self.history.as_deref()
+    }
+
+    /// Choose how incoming influences combine into peer pressure
+    pub fn set_peer_aggregation(&mut self, aggregation: PeerAggregation) {
+        self.peer_aggregation = aggregation;
+    }
+
+    /// Choose how party discipline is computed within a round.
+    ///
+    /// By default (false) each member sees the party average of the live
+    /// scores at the moment they update, so members updated later in a round
+    /// react to co-partisans who already moved. When true, party averages
+    /// are computed once from the round-start scores and every member of a
+    /// party feels the same discipline signal that round.
+    pub fn set_freeze_party_average(&mut self, freeze: bool) {
+        self.freeze_party_average = freeze;
+    }
+
+    /// Override a party's discipline for this simulator only, leaving the
+    /// congress untouched, e.g. to ask how the vote goes under a stronger
+    /// whip. Takes effect from the next update on.
+    pub fn set_party_discipline(
+        &mut self,
+        party_id: &str,
+        discipline: f64,
+    ) -> Result<(), SimError> {
+        let party_idx = self
+            .congress
+            .parties()
+            .iter()
+            .position(|p| p.id == party_id)
+            .ok_or_else(|| SimError::UnknownParty(party_id.to_string()))?;
+        self.discipline_overrides.insert(party_idx, discipline);
+        Ok(())
+    }
+
+    /// Edit one member's bias, swing and ideal for this simulator only (the
+    /// congress itself is untouched) and recompute just that member's
+    /// initial score, which also becomes their current score. Edges and
+    /// every other member's score are unaffected until the next `run` or
+    /// `step`, so an interactive tool can re-run cheaply after each edit.
+    /// A proximity matrix keeps the old ideal until `set_proximity_influence`
+    /// is called again. Nothing changes if `ideal` does not match the
+    /// proposal's length or `swing` lies outside [0, 1].
+    pub fn update_member(
+        &mut self,
+        node_idx: NodeIndex,
+        bias: f64,
+        swing: f64,
+        ideal: DVector<f64>,
+    ) -> Result<(), SimError> {
+        let id = &self.congress.graph[node_idx].id;
+        if ideal.len() != self.proposal.len() {
+            return Err(SimError::DimensionMismatch {
+                member: id.clone(),
+                ideal: ideal.len(),
+                proposal: self.proposal.len(),
+            });
+        }
+        if !(0.0..=1.0).contains(&swing) {
+            return Err(SimError::SwingOutOfRange {
+                member: id.clone(),
+                swing,
+            });
+        }
+
+        let mut node = self.member(node_idx).clone();
+        node.bias = bias;
+        node.swing = swing;
+        node.ideal = ideal;
+
+        let baseline = self.baseline(&node, &self.proposal);
+        self.initial_scores[node_idx.index()] = baseline;
+        self.scores[node_idx.index()] = baseline;
+        self.edited.insert(node_idx, node);
+        Ok(())
+    }
+
+    /// Force a member's final vote (1, 0 or -1) whatever their score, e.g.
+    /// "assume the Speaker votes yes". The pin is applied last at every
+    /// finalization and projected tally, overriding the member's personal
+    /// threshold, `conflict_abstain` and vote block; it counts in `passes`
+    /// like any other vote. The member's score still evolves and exerts
+    /// pressure as usual; see `pin_vote_locked` to hold it too. Pinning the
+    /// same member again replaces the earlier pin.
+    pub fn pin_vote(&mut self, node_idx: NodeIndex, vote: i8) {
+        assert!(
+            (-1..=1).contains(&vote),
+            "vote must be -1, 0 or 1, got {}",
+            vote
+        );
+        self.pins.insert(node_idx, vote);
+        self.locked_scores.remove(&node_idx);
+    }
+
+    /// Like `pin_vote`, but also lock the member's score at `vote` so they
+    /// stop being swayed from the next update on. A locked yes or no pushes
+    /// others steadily toward that side; a locked abstention holds the score
+    /// at 0.0, which exerts no opinion at all.
+    pub fn pin_vote_locked(&mut self, node_idx: NodeIndex, vote: i8) {
+        self.pin_vote(node_idx, vote);
+        self.locked_scores.insert(node_idx);
+        self.scores[node_idx.index()] = vote as f64;
+    }
+
+    /// `pin_vote` by member ID, for whip counts written in terms of names.
+    /// Freezes accumulate, one per member; to also hold the member's score
+    /// during rounds, look them up with `CongressGraph::node_index` and use
+    /// `pin_vote_locked`.
+    pub fn freeze_vote(&mut self, id: &str, vote: i8) -> Result<(), SimError> {
+        let node_idx = self
+            .congress
+            .node_index(id)
+            .ok_or_else(|| SimError::UnknownMember(id.to_string()))?;
+        self.pin_vote(node_idx, vote);
+        Ok(())
+    }
+
+    /// Add an external push (a lobbying campaign, a bought vote) to named
+    /// members' current scores, keyed by member ID; positive values push
+    /// toward yes. Call this before `run` or between steps. Only current
+    /// scores move, so self-loops still anchor to the unpressured baseline
+    /// and influence can wear the push down over later rounds. Nothing is
+    /// applied if any ID is unknown.
+    pub fn apply_external_pressure(
+        &mut self,
+        pressures: &HashMap<String, f64>,
+    ) -> Result<(), SimError> {
+        let targets = pressures
+            .iter()
+            .map(|(id, &pressure)| {
+                self.congress
+                    .node_index(id)
+                    .map(|node_idx| (node_idx, pressure))
+                    .ok_or_else(|| SimError::UnknownMember(id.clone()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for (node_idx, pressure) in targets {
+            self.scores[node_idx.index()] += pressure;
+        }
+        Ok(())
+    }
+
+    /// A member's score before any social influence, per the score formula
+    /// unless they declared an initial vote
+    fn baseline(&self, node: &Node, proposal: &DVector<f64>) -> f64 {
+        if let Some(vote) = node.initial_vote {
+            return vote as f64 * DECLARED_VOTE_SCORE;
+        }
+        let alignment = self.alignment(&node.ideal, proposal);
+        match self.score_init {
+            ScoreInit::AddBias => alignment + node.bias,
+            ScoreInit::MulBias => alignment * (1.0 + node.bias),
+            ScoreInit::AlignmentOnly => alignment,
+        }
+    }
+
+    /// Alignment of an ideal with a proposal, relative to the status quo if
+    /// one is set
+    fn alignment(&self, ideal: &DVector<f64>, proposal: &DVector<f64>) -> f64 {
+        match &self.status_quo {
+            Some(status_quo) => status_quo_alignment(ideal, proposal, status_quo),
+            None => self.congress.alignment(ideal, proposal),
+        }
+    }
+
+    /// A member's attributes, including edits made through `update_member`
+    fn member(&self, node_idx: NodeIndex) -> &Node {
+        self.edited
+            .get(&node_idx)
+            .unwrap_or(&self.congress.graph[node_idx])
+    }
+
+    /// Each member's baseline score (alignment plus bias) that self-loops
+    /// anchor to, indexed by node index
+    pub fn initial_scores(&self) -> &[f64] {
+        &self.initial_scores
+    }
+
+    /// Runs the simulation for specified number of rounds, drawing the
+    /// update order from the thread-local RNG
+    pub fn run(&mut self, max_rounds: usize, threshold: f64) {
+        let mut rng = rand::rng();
+        self.run_with_rng(max_rounds, threshold, &mut rng);
+    }
+
+    /// Same as `run`, drawing the update order from `rng`. Threading one
+    /// seeded RNG through a batch of runs makes the whole batch reproducible
+    /// from a single master seed while keeping the runs independent.
+    pub fn run_with_rng<R: Rng + ?Sized>(
+        &mut self,
+        max_rounds: usize,
+        threshold: f64,
+        rng: &mut R,
+    ) {
+        for round in 1..=max_rounds {
+            // Only snapshot scores when someone will see the delta
+            let before = log::log_enabled!(log::Level::Debug).then(|| self.scores.clone());
+            self.round(rng);
+            if let Some(before) = before {
+                let delta = before
+                    .iter()
+                    .zip(&self.scores)
+                    .map(|(a, b)| (a - b).abs())
+                    .fold(0.0, f64::max);
+                log::debug!(
+                    "round {}/{}: max score change {:.6}",
+                    round,
+                    max_rounds,
+                    delta
+                );
+            }
+        }
+
+        // Finalize votes using threshold
+        self.finalize(threshold);
+        let tally = self.tally();
+        log::debug!(
+            "finalized after {} rounds: {} yes, {} no, {} abstain",
+            max_rounds,
+            tally.yes,
+            tally.no,
+            tally.abstain
+        );
+    }
+
+    /// Run until the largest per-round score change drops below
+    /// `tolerance`, at most `max_rounds` rounds, then finalize votes as `run`
+    /// does. A converging run keeps shrinking its round deltas; if
+    /// `OSCILLATION_WINDOW` rounds pass without a new smallest delta, scores
+    /// are cycling and the run stops early with `Oscillating`, whose final
+    /// votes reflect an arbitrary point in the cycle. The update order is
+    /// drawn from `rng` as in `run_with_rng`, so a seeded RNG makes the
+    /// status reproducible.
+    pub fn run_until_converged<R: Rng + ?Sized>(
+        &mut self,
+        max_rounds: usize,
+        threshold: f64,
+        tolerance: f64,
+        rng: &mut R,
+    ) -> ConvergenceStatus {
+        let mut status = ConvergenceStatus::MaxRoundsReached;
+        let mut smallest_delta = f64::INFINITY;
+        let mut stalled = 0;
+        for round in 1..=max_rounds {
+            let before = self.scores.clone();
+            self.round(rng);
+            let delta = before
+                .iter()
+                .zip(&self.scores)
+                .map(|(a, b)| (a - b).abs())
+                .fold(0.0, f64::max);
+
+            if delta < tolerance {
+                status = ConvergenceStatus::Converged { rounds: round };
+                break;
+            }
+            if delta < smallest_delta {
+                smallest_delta = delta;
+                stalled = 0;
+            } else {
+                stalled += 1;
+                if stalled >= OSCILLATION_WINDOW {
+                    log::warn!(
+                        "scores oscillate: no smaller change than {:.6} for {} rounds",
+                        smallest_delta,
+                        stalled
+                    );
+                    status = ConvergenceStatus::Oscillating {
+                        detected_round: round,
+                    };
+                    break;
+                }
+            }
+        }
+        self.finalize(threshold);
+        status
+    }
+
+    /// Like `run`, but members are updated in insertion (`node_indices()`)
+    /// order every round, so results are fully deterministic without a
+    /// seed. Same as `set_order_mode(OrderMode::Fixed)` followed by `run`;
+    /// the fixed order stays in effect for later calls.
+    pub fn run_ordered(&mut self, max_rounds: usize, threshold: f64) {
+        self.order_mode = OrderMode::Fixed;
+        // Never drawn from in fixed order; only needed to satisfy the signature
+        let mut unused = StdRng::seed_from_u64(0);
+        self.run_with_rng(max_rounds, threshold, &mut unused);
+    }
+
+    /// Like `run`, but each member's peer pressure is an exponential moving
+    /// average across rounds, `p_t = alpha * p_new + (1 - alpha) * p_{t-1}`,
+    /// starting from the first round's pressure. Lower `alpha` (in (0, 1])
+    /// damps round-to-round oscillation; `alpha = 1.0` is plain `run`. The
+    /// smoothing stays in effect for later `run` or `step` calls.
+    pub fn run_with_ema(&mut self, max_rounds: usize, threshold: f64, ema_alpha: f64) {
+        self.ema_alpha = ema_alpha;
+        self.run(max_rounds, threshold);
+    }
+
+    /// Like `run`, but edges between two members of the same party have
+    /// their effective weight multiplied by `boost`, modelling in-group
+    /// trust (self-loops are unaffected). `boost = 1.0` is plain `run`. The
+    /// boost stays in effect for later `run` or `step` calls.
+    pub fn run_with_copartisan_boost(&mut self, max_rounds: usize, threshold: f64, boost: f64) {
+        self.copartisan_boost = boost;
+        self.run(max_rounds, threshold);
+    }
+
+    /// Like `run`, but members abstain probabilistically depending on their
+    /// conviction, see `DecisionRule::SoftAbstain`. The update order and the
+    /// abstention draws both come from `rng`, so a seeded RNG gives
+    /// reproducible votes.
+    pub fn run_soft_abstain<R: Rng + ?Sized>(
+        &mut self,
+        max_rounds: usize,
+        threshold: f64,
+        steepness: f64,
+        rng: &mut R,
+    ) {
+        for _ in 0..max_rounds {
+            self.round(rng);
+        }
+        let rule = DecisionRule::SoftAbstain {
+            t: threshold,
+            steepness,
+        };
+        self.finalize_with(rule, rng);
+    }
+
+    /// Copies the full simulation state (scores, votes, proposal, history and
+    /// settings) into an independent simulator sharing the same congress, so
+    /// several scenarios can be explored from a common mid-run state
+    pub fn fork(&self) -> Simulator<'a> {
+        self.clone()
+    }
+
+    /// Runs a single round of social influence without finalizing votes
+    pub fn step(&mut self) {
+        let mut rng = rand::rng();
+        self.round(&mut rng);
+    }
+
+    /// Replace the proposal mid-simulation, modelling a floor amendment.
+    ///
+    /// Each member's baseline is recomputed as alignment with the new
+    /// proposal plus bias, and their score becomes
+    /// `(1 - social_weight) * baseline + social_weight * current_score`.
+    /// `social_weight = 0.0` restarts from the new baseline, discarding the
+    /// social dynamics so far; `1.0` keeps current scores untouched so only
+    /// later rounds see the new proposal. The new baseline also replaces the
+    /// initial score that self-loops anchor to. Call `run` or `step` again
+    /// to continue.
+    pub fn amend_proposal(&mut self, new_proposal: DVector<f64>, social_weight: f64) {
+        for node_idx in self.congress.graph.node_indices() {
+            let baseline = self.baseline(self.member(node_idx), &new_proposal);
+            let i = node_idx.index();
+            self.initial_scores[i] = baseline;
+            self.scores[i] = (1.0 - social_weight) * baseline + social_weight * self.scores[i];
+        }
+        self.proposal = new_proposal;
+    }
+
+    /// Thresholds the current scores into a tally without touching the
+    /// finalized votes, giving a mid-simulation whip count
+    pub fn projected_tally(&self, threshold: f64) -> VoteTally {
+        VoteTally::from_votes(self.threshold_votes(threshold))
+    }
+
+    /// Finalize votes with the given decision rule.
+    /// Logistic draws come from `rng`, so a seeded RNG gives reproducible votes.
+    pub fn finalize_with<R: Rng + ?Sized>(&mut self, rule: DecisionRule, rng: &mut R) {
+        match rule {
+            DecisionRule::HardThreshold { t } => self.finalize(t),
+            DecisionRule::Logistic { temperature } => {
+                for node_idx in self.congress.graph.node_indices() {
+                    let score = self.scores[node_idx.index()];
+                    let p_yes = sigmoid(score / temperature);
+                    self.votes[node_idx.index()] = if rng.random_bool(p_yes) { 1 } else { -1 };
+                }
+            }
+            DecisionRule::SoftAbstain { t, steepness } => {
+                for node_idx in self.congress.graph.node_indices() {
+                    let score = self.scores[node_idx.index()];
+                    let p_abstain = sigmoid(steepness * (t - score.abs()));
+                    self.votes[node_idx.index()] = if rng.random_bool(p_abstain) {
+                        0
+                    } else {
+                        threshold_vote(score, 0.0)
+                    };
+                }
+            }
+        }
+        for (&node_idx, &vote) in &self.pins {
+            self.votes[node_idx.index()] = vote;
+        }
+    }
+
+    /// Update every node once, in the configured order
+    fn round<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        let mut order: Vec<NodeIndex> = self.congress.graph.node_indices().collect();
+        match self.order_mode {
+            OrderMode::Shuffled => order.shuffle(rng),
+            OrderMode::Fixed => {}
+            OrderMode::ReverseFixed => order.reverse(),
+        }
+
+        // Party lines as of round start, in O(N) total. Unless frozen they
+        // follow members' sign changes incrementally below, so a member's
+        // party pressure costs O(parties they belong to), not O(party size).
+        let mut party_lines: Vec<f64> = self
+            .congress
+            .parties()
+            .iter()
+            .map(|party| self.party_line(party))
+            .collect();
+
+        for &node_idx in &order {
+            // Calculate peer pressure from influences
+            let mut peer_pressure = self.calculate_peer_pressure(node_idx);
+            if self.rounds_done > 0 {
+                let previous = self.smoothed_pressure[node_idx.index()];
+                peer_pressure = self.ema_alpha * peer_pressure + (1.0 - self.ema_alpha) * previous;
+            }
+            self.smoothed_pressure[node_idx.index()] = peer_pressure;
+
+            // Calculate party discipline pressure
+            let party_pressure = self.calculate_party_pressure(node_idx, Some(&party_lines));
+
+            // Implicit pressure from ideologically close members, if enabled
+            let proximity_pressure = self.calculate_proximity_pressure(node_idx);
+
+            // Salience-weighted view of the proposal, if a schedule is set
+            let salience_pressure = self.calculate_salience_pressure(node_idx);
+
+            // Update node score
+            let social_pressure =
+                peer_pressure + party_pressure + proximity_pressure + salience_pressure;
+            self.cumulative_pressure[node_idx.index()] += social_pressure;
+            let old_sign = opinion(self.scores[node_idx.index()]);
+            self.update_node_score(node_idx, social_pressure);
+            let new_sign = opinion(self.scores[node_idx.index()]);
+
+            if !self.freeze_party_average && new_sign != old_sign {
+                for &(party_idx, _) in self.congress.party_memberships(node_idx) {
+                    let party = &self.congress.parties()[party_idx];
+                    if party.position.is_none() {
+                        party_lines[party_idx] +=
+                            (new_sign - old_sign) / party.members.len() as f64;
+                    }
+                }
+            }
+        }
+
+        if let Some(history) = &mut self.history {
+            history.push(self.scores.clone());
+        }
+        self.rounds_done += 1;
+    }
+
+    /// Turn current scores into final votes using threshold
+    fn finalize(&mut self, threshold: f64) {
+        self.votes = self.threshold_votes(threshold);
+    }
+
+    /// Threshold the current scores into votes, indexed by node index, using
+    /// each member's personal threshold where set. Members conflicted with
+    /// their party beyond their `conflict_abstain` tolerance abstain, and
+    /// members of a vote block all take the vote of the block's mean score
+    /// under `threshold`. Pinned votes override all of these.
+    fn threshold_votes(&self, threshold: f64) -> Vec<i8> {
+        let mut votes: Vec<i8> = self
+            .congress
+            .graph
+            .node_indices()
+            .map(|node_idx| {
+                let node = self.member(node_idx);
+                if let Some(tolerance) = node.conflict_abstain {
+                    let own = self.alignment(&node.ideal, &self.proposal);
+                    let party = self.calculate_party_pressure(node_idx, None);
+                    if own * party.signum() < -tolerance && party != 0.0 {
+                        return 0;
+                    }
+                }
+                let score = self.scores[node_idx.index()];
+                threshold_vote(score, node.threshold.unwrap_or(threshold))
+            })
+            .collect();
+        for block in self.congress.vote_blocks() {
+            if block.is_empty() {
+                continue;
+            }
+            let mean =
+                block.iter().map(|m| self.scores[m.index()]).sum::<f64>() / block.len() as f64;
+            let vote = threshold_vote(mean, threshold);
+            for member in block {
+                votes[member.index()] = vote;
+            }
+        }
+        for (&node_idx, &vote) in &self.pins {
+            votes[node_idx.index()] = vote;
+        }
+        votes
+    }
+
+    /// Solve directly for the equilibrium of the linear influence model.
+    ///
+    /// This is the model `run` iterates under `PeerAggregation::Linear`:
+    /// sources contribute their raw scores instead of their signs, and each
+    /// member keeps `1 - swing` of their initial score, so the fixed point
+    /// solves `(I - diag(swing) * W_norm) x = (1 - swing) * initial`, where
+    /// `W_norm` holds incoming edge weights normalized per target. Self-loops
+    /// anchor to the initial score as in `run`. Party discipline, proximity
+    /// and salience are not part of the linear model, so without them a
+    /// linear `run` converges to this result. The result is exact (via LU)
+    /// rather than iterative, and indexed by node index. Returns `None` if
+    /// the system is singular, e.g. a closed influence cycle whose members
+    /// all have `swing = 1`.
+    pub fn solve_linear_equilibrium(&self) -> Option<DVector<f64>> {
+        let n = self.congress.graph.node_count();
+        if n == 0 {
+            // nalgebra's LU cannot handle a 0x0 system
+            return Some(DVector::zeros(0));
+        }
+        let mut system = DMatrix::<f64>::identity(n, n);
+        let mut rhs = DVector::<f64>::zeros(n);
+
+        for node_idx in self.congress.graph.node_indices() {
+            let i = node_idx.index();
+            let swing = self.member(node_idx).swing;
+            let initial = self.initial_scores[i];
+            rhs[i] = (1.0 - swing) * initial;
+
+            let incoming: Vec<_> = self
+                .congress
+                .graph
+                .edges_directed(node_idx, petgraph::Direction::Incoming)
+                .collect();
+            let total_weight: f64 = incoming.iter().map(|&e| self.edge_influence(e)).sum();
+            if total_weight.abs() <= f64::EPSILON {
+                continue;
+            }
+
+            for edge in incoming {
+                let w = swing * self.edge_influence(edge) / total_weight;
+                if edge.source() == node_idx {
+                    rhs[i] += w * initial;
+                } else {
+                    system[(i, edge.source().index())] -= w;
+                }
+            }
+        }
+
+        system.lu().solve(&rhs)
+    }
+
+    /// Calculate peer pressure from incoming influences, each source
+    /// contributing its `opinion` (its raw score under `Linear`).
+    /// Self-loops contribute the member's initial score instead, so a
+    /// heavy self-weight makes the member resist being pulled away from it.
+    fn calculate_peer_pressure(&self, node_idx: NodeIndex) -> f64 {
+        let express = |score: f64| match self.peer_aggregation {
+            PeerAggregation::Linear => score,
+            _ => opinion(score),
+        };
+        // (source opinion, edge weight) for every incoming influence
+        let mut influences: Vec<(f64, f64)> = self
+            .congress
+            .graph
+            .edges_directed(node_idx, petgraph::Direction::Incoming)
+            .map(|edge| {
+                let source_idx = edge.source();
+                let source_score = if source_idx == node_idx {
+                    express(self.initial_scores[node_idx.index()])
+                } else {
+                    express(self.scores[source_idx.index()])
+                };
+                (source_score, self.edge_influence(edge))
+            })
+            .collect();
+
+        match self.peer_aggregation {
+            PeerAggregation::WeightedMean | PeerAggregation::Linear => {
+                let mut weighted_sum = 0.0;
+                let mut total_weight = 0.0;
+                for &(source_score, weight) in &influences {
+                    weighted_sum += weight * source_score;
+                    total_weight += weight;
+                }
+
+                if total_weight.abs() > f64::EPSILON {
+                    weighted_sum / total_weight
+                } else {
+                    0.0
+                }
+            }
+            PeerAggregation::Max => influences
+                .iter()
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|&(source_score, _)| source_score)
+                .unwrap_or(0.0),
+            PeerAggregation::Median => weighted_median(&mut influences),
+        }
+    }
+
+    /// Calculate pressure from ideological proximity to every other member
+    fn calculate_proximity_pressure(&self, node_idx: NodeIndex) -> f64 {
+        let Some((coefficient, similarity)) = &self.proximity else {
+            return 0.0;
+        };
+        let n = self.scores.len();
+        if n < 2 {
+            return 0.0;
+        }
+
+        let i = node_idx.index();
+        let pull: f64 = (0..n)
+            .map(|j| similarity[(i, j)] * opinion(self.scores[j]))
+            .sum();
+        coefficient * pull / (n - 1) as f64
+    }
+
+    /// Calculate the re-injected, salience-weighted alignment for this round
+    fn calculate_salience_pressure(&self, node_idx: NodeIndex) -> f64 {
+        let Some((weight, schedule)) = &self.salience else {
+            return 0.0;
+        };
+        let salience = &schedule[self.rounds_done.min(schedule.len() - 1)];
+        let node = self.member(node_idx);
+        weight * (weighted_cosine_similarity(&node.ideal, &self.proposal, salience) + node.bias)
+    }
+
+    /// Effective weight of an influence edge: its weight scaled by the
+    /// source member's credibility and, between co-partisans, by the
+    /// co-partisan boost; zero if the edge is conditional on dimensions this
+    /// proposal does not touch
+    fn edge_influence(&self, edge: EdgeReference<'_, f64>) -> f64 {
+        if !self.congress.edge_is_active(edge.id(), &self.proposal) {
+            return 0.0;
+        }
+        let mut influence = *edge.weight() * self.congress.graph[edge.source()].credibility;
+        let (source_party, target_party) = (
+            self.congress.get_party_index(edge.source()),
+            self.congress.get_party_index(edge.target()),
+        );
+        if edge.source() != edge.target() && source_party.is_some() && source_party == target_party
+        {
+            influence *= self.copartisan_boost;
+        }
+        influence
+    }
+
+    /// Calculate party discipline pressure, blended across the member's
+    /// parties by membership weight.
+    /// Uses the given party lines (indexed by party) when `party_lines` is
+    /// given, otherwise computes each line from the live scores.
+    fn calculate_party_pressure(&self, node_idx: NodeIndex, party_lines: Option<&[f64]>) -> f64 {
+        let memberships = self.congress.party_memberships(node_idx);
+        let total_weight: f64 = memberships.iter().map(|&(_, w)| w).sum();
+        if total_weight <= 0.0 {
+            return 0.0; // No party affiliation
+        }
+
+        let mut pressure = 0.0;
+        for &(party_idx, weight) in memberships {
+            let party = &self.congress.parties()[party_idx];
+            let line = match party_lines {
+                Some(lines) => lines[party_idx],
+                None => self.party_line(party),
+            };
+            let discipline = self
+                .discipline_overrides
+                .get(&party_idx)
+                .copied()
+                .unwrap_or(party.discipline);
+            pressure += weight * discipline * line;
+        }
+        pressure / total_weight
+    }
+
+    /// Direction the whip pulls in: the sign of the party position's
+    /// alignment with the proposal if the party has one, else the members'
+    /// average current vote sign
+    fn party_line(&self, party: &Party) -> f64 {
+        match &party.position {
+            Some(position) => opinion(self.alignment(position, &self.proposal)),
+            None => self.party_mean(party),
+        }
+    }
+
+    /// Average current opinion of a party's members
+    fn party_mean(&self, party: &Party) -> f64 {
+        let mut total_vote = 0.0;
+        let mut count = 0;
+
+        for &member in &party.members {
+            total_vote += opinion(self.scores[member.index()]);
+            count += 1;
+        }
+
+        // Avoid division by zero for empty parties
+        if count == 0 {
+            0.0
+        } else {
+            total_vote / count as f64
+        }
+    }
+
+    /// Update node score based on social pressure, blending it with the
+    /// current score (the initial score under `PeerAggregation::Linear`)
+    fn update_node_score(&mut self, node_idx: NodeIndex, social_pressure: f64) {
+        if self.locked_scores.contains(&node_idx) {
+            self.scores[node_idx.index()] = self.pins[&node_idx] as f64;
+            return;
+        }
+        let swing_factor = self.member(node_idx).swing;
+        let anchor = match self.peer_aggregation {
+            PeerAggregation::Linear => self.initial_scores[node_idx.index()],
+            _ => self.scores[node_idx.index()],
+        };
+
+        let mut new_score = (1.0 - swing_factor) * anchor + swing_factor * social_pressure;
+        if let Some((min, max)) = self.clamp {
+            new_score = new_score.clamp(min, max);
+        }
+        debug_assert!(
+            new_score.is_finite(),
+            "score of `{}` became {}",
+            self.congress.graph[node_idx].id,
+            new_score
+        );
+        self.scores[node_idx.index()] = new_score;
+    }
+
+    /// Get final votes of all nodes,
+    /// return a HashMap with node ID as key
+    pub fn get_votes(&self) -> std::collections::HashMap<String, i8> {
+        let mut map = std::collections::HashMap::new();
+        for node_idx in self.congress.graph.node_indices() {
+            let node = &self.congress.graph[node_idx];
+            let vote = self.votes[node_idx.index()];
+            map.insert(node.id.clone(), vote);
+        }
+        map
+    }
+
+    /// Get the total social pressure applied to each node across all rounds,
+    /// keyed by node ID. Only populated after `run` (or `step`); all zeros before.
+    pub fn cumulative_pressure(&self) -> HashMap<String, f64> {
+        self.congress
+            .graph
+            .node_indices()
+            .map(|idx| {
+                (
+                    self.congress.graph[idx].id.clone(),
+                    self.cumulative_pressure[idx.index()],
+                )
+            })
+            .collect()
+    }
+
+    /// Each member's probability of voting yes, `sigmoid(steepness * score)`
+    /// of their current score, keyed by node ID. A soft view of the vote
+    /// where marginal members sit near 0.5 instead of being cut at a
+    /// threshold; the same transform `DecisionRule::Logistic` samples from
+    /// with `temperature = 1 / steepness`.
+    pub fn support_probabilities(&self, steepness: f64) -> HashMap<String, f64> {
+        self.congress
+            .graph
+            .node_indices()
+            .map(|idx| {
+                (
+                    self.congress.graph[idx].id.clone(),
+                    sigmoid(steepness * self.scores[idx.index()]),
+                )
+            })
+            .collect()
+    }
+
+    /// IDs of members whose initial score (alignment + bias) has magnitude
+    /// below `epsilon`: the persuadable middle that floats with social pressure
+    pub fn undecided_members(&self, epsilon: f64) -> Vec<String> {
+        self.congress
+            .graph
+            .node_indices()
+            .filter(|idx| self.initial_scores[idx.index()].abs() < epsilon)
+            .map(|idx| self.congress.graph[idx].id.clone())
+            .collect()
+    }
+
+    /// Get (id, final score, vote) for every node, sorted by score from
+    /// strongest supporter to strongest opponent. Ties keep node index order.
+    pub fn ranked_members(&self) -> Vec<(String, f64, i8)> {
+        let mut ranked: Vec<NodeIndex> = self.congress.graph.node_indices().collect();
+        ranked.sort_by(|a, b| {
+            self.scores[b.index()]
+                .total_cmp(&self.scores[a.index()])
+                .then(a.index().cmp(&b.index()))
+        });
+        ranked
+            .into_iter()
+            .map(|idx| {
+                (
+                    self.congress.graph[idx].id.clone(),
+                    self.scores[idx.index()],
+                    self.votes[idx.index()],
+                )
+            })
+            .collect()
+    }
+
+    /// Social welfare of the proposal: the sum over members of their policy
+    /// alignment with it (personal bias and social pressure excluded)
+    pub fn social_welfare(&self) -> f64 {
+        self.congress
+            .graph
+            .node_indices()
+            .map(|idx| self.alignment(&self.member(idx).ideal, &self.proposal))
+            .sum()
+    }
+
+    /// Count members whose current score falls in each of `bins` equal-width
+    /// bins over `range = (min, max)`; out-of-range scores go to the end
+    /// bins. Returns an empty vector if `bins` is zero.
+    pub fn score_histogram(&self, bins: usize, range: (f64, f64)) -> Vec<usize> {
+        let (min, max) = range;
+        let mut counts = vec![0; bins];
+        if bins == 0 {
+            return counts;
+        }
+
+        let width = (max - min) / bins as f64;
+        for &score in &self.scores {
+            let bin = if width > 0.0 {
+                ((score - min) / width)
+                    .floor()
+                    .clamp(0.0, (bins - 1) as f64) as usize
+            } else {
+                0
+            };
+            counts[bin] += 1;
+        }
+        counts
+    }
+
+    /// Two-party polarization index in [0, 1]: the absolute difference
+    /// between the parties' mean final scores, divided by the spread
+    /// (max - min) of all final scores. 0.0 if every score is equal.
+    /// Returns `None` if either party is unknown or has no members.
+    pub fn polarization(&self, party_a: &str, party_b: &str) -> Option<f64> {
+        let mean_score = |id: &str| {
+            let party = self.congress.parties().iter().find(|p| p.id == id)?;
+            if party.members.is_empty() {
+                return None;
+            }
+            let total: f64 = party.members.iter().map(|m| self.scores[m.index()]).sum();
+            Some(total / party.members.len() as f64)
+        };
+        let gap = (mean_score(party_a)? - mean_score(party_b)?).abs();
+
+        let max = self
+            .scores
+            .iter()
+            .copied()
+            .fold(f64::NEG_INFINITY, f64::max);
+        let min = self.scores.iter().copied().fold(f64::INFINITY, f64::min);
+        let spread = max - min;
+        Some(if spread > 0.0 { gap / spread } else { 0.0 })
+    }
+
+    /// IDs (sorted) of members who voted against their party's majority.
+    /// Abstentions are ignored on both sides: abstainers are never rebels,
+    /// and a party split evenly between yes and no has no majority to rebel
+    /// against.
+    pub fn party_rebels(&self) -> Vec<String> {
+        let mut balance = vec![0i64; self.congress.parties().len()];
+        for node_idx in self.congress.graph.node_indices() {
+            if let Some(party_idx) = self.congress.get_party_index(node_idx) {
+                balance[party_idx] += self.votes[node_idx.index()] as i64;
+            }
+        }
+
+        let mut rebels: Vec<String> = self
+            .congress
+            .members()
+            .filter(|&(node_idx, _)| {
+                let vote = self.votes[node_idx.index()];
+                self.congress.get_party_index(node_idx).is_some_and(|p| {
+                    vote != 0 && balance[p] != 0 && vote as i64 != balance[p].signum()
+                })
+            })
+            .map(|(_, member)| member.id.clone())
+            .collect();
+        rebels.sort();
+        rebels
+    }
+
+    /// Fraction of party members whose final vote matches the sign of their
+    /// party's average final score (abstain if that average is exactly zero).
+    /// Independents are excluded; returns 0.0 if no member has a party.
+    pub fn party_line_rate(&self) -> f64 {
+        let lines: Vec<i8> = self
+            .congress
+            .parties()
+            .iter()
+            .map(|party| {
+                let total: f64 = party.members.iter().map(|m| self.scores[m.index()]).sum();
+                threshold_vote(total / party.members.len().max(1) as f64, 0.0)
+            })
+            .collect();
+
+        let mut partied = 0usize;
+        let mut on_line = 0usize;
+        for node_idx in self.congress.graph.node_indices() {
+            if let Some(party_idx) = self.congress.get_party_index(node_idx) {
+                partied += 1;
+                if self.votes[node_idx.index()] == lines[party_idx] {
+                    on_line += 1;
+                }
+            }
+        }
+
+        if partied == 0 {
+            0.0
+        } else {
+            on_line as f64 / partied as f64
+        }
+    }
+
+    /// Get the counts of yes, no and abstain final votes
+    pub fn tally(&self) -> VoteTally {
+        VoteTally::from_votes(self.votes.iter().copied())
+    }
+
+    /// Shannon entropy (in bits) of the yes/no/abstain proportions.
+    /// 0.0 for a unanimous body, log2(3) for an even three-way split.
+    pub fn vote_entropy(&self) -> f64 {
+        let tally = self.tally();
+        let total = tally.total_all() as f64;
+        if total == 0.0 {
+            return 0.0;
+        }
+        [tally.yes, tally.no, tally.abstain]
+            .iter()
+            .filter(|&&count| count > 0)
+            .map(|&count| {
+                let p = count as f64 / total;
+                -p * p.log2()
+            })
+            .sum()
+    }
+
+    /// Bundle the proposal, final votes and scores, tally, outcome, social
+    /// welfare and polarization
+    pub fn result(&self, rule: Majority) -> SimulationResult {
+        let mut votes = BTreeMap::new();
+        let mut scores = BTreeMap::new();
+        for node_idx in self.congress.graph.node_indices() {
+            let id = &self.congress.graph[node_idx].id;
+            votes.insert(id.clone(), self.votes[node_idx.index()]);
+            scores.insert(id.clone(), self.scores[node_idx.index()]);
+        }
+
+        SimulationResult {
+            proposal: self.proposal.iter().copied().collect(),
+            votes,
+            scores,
+            tally: self.tally(),
+            passed: self.passes(rule),
+            social_welfare: self.social_welfare(),
+            polarization: self.main_party_polarization(),
+        }
+    }
+
+    /// Polarization between the two largest parties (earlier parties win
+    /// size ties)
+    fn main_party_polarization(&self) -> Option<f64> {
+        let mut parties: Vec<&Party> = self.congress.parties().iter().collect();
+        parties.sort_by_key(|p| std::cmp::Reverse(p.members.len()));
+        match parties[..] {
+            [a, b, ..] => self.polarization(&a.id, &b.id),
+            _ => None,
+        }
+    }
+
+    /// Get the vote result(proposal passes or not).
+    /// Always false for an empty congress, whatever the rule.
+    pub fn passes(&self, rule: Majority) -> bool {
+        self.vote_outcome(rule).passed
+    }
+
+    /// Like `passes`, but also reports the vote counts and the margin by
+    /// which the proposal cleared or missed the rule's threshold
+    pub fn vote_outcome(&self, rule: Majority) -> VoteOutcome {
+        let outcome = self.tally().outcome(rule);
+        log::debug!("{:?} under {:?}", outcome, rule);
+        outcome
+    }
+
+    /// Like `passes`, but a yes fraction exactly on the rule's threshold
+    /// (e.g. 50% under `SIMPLE`) is decided by `tiebreak` instead of
+    /// failing. "Exactly" means within an absolute tolerance of 1e-9 of the
+    /// threshold fraction (1/2, 2/3 or a custom rule's `fraction`), which
+    /// absorbs rounding in the division. `UNANIMITY` has no tie case. A
+    /// presiding member that is not in the congress breaks the tie as a fail.
+    pub fn passes_with_tiebreak(&self, rule: Majority, tiebreak: &TieBreak) -> bool {
+        let tally = self.tally();
+        if tally.passes(rule) {
+            return true;
+        }
+        if rule == Majority::UNANIMITY {
+            return false;
+        }
+        let (share, needed) = tally.yes_share(rule);
+        if tally.yes == 0 || (share - needed).abs() > TIE_TOLERANCE {
+            return false;
+        }
+        match tiebreak {
+            TieBreak::Fail => false,
+            TieBreak::Pass => true,
+            TieBreak::PresidingMember(id) => self
+                .congress
+                .node_index(id)
+                .is_some_and(|idx| self.votes[idx.index()] == 1),
+        }
+    }
+
+    /// Like `passes`, but the vote is invalid (returns false) unless at least
+    /// `quorum` members are present. Yes and no voters are always present;
+    /// abstainers count toward quorum only if `abstentions_count` is true.
+    pub fn passes_with_quorum(
+        &self,
+        rule: Majority,
+        quorum: usize,
+        abstentions_count: bool,
+    ) -> bool {
+        let tally = self.tally();
+        let present = if abstentions_count {
+            tally.total_all()
+        } else {
+            tally.total_cast()
+        };
+        if present < quorum {
+            log::info!("quorum not met: {} present, {} required", present, quorum);
+            return false;
+        }
+        self.passes(rule)
+    }
+
+    /// Get final vote of a node
+    pub fn get_vote(&self, node_idx: NodeIndex) -> i8 {
+        self.votes[node_idx.index()]
+    }
+
+    /// Get current score of a node
+    pub fn get_score(&self, node_idx: NodeIndex) -> f64 {
+        self.scores[node_idx.index()]
+    }
+
+    /// Get current scores scaled into [-1, 1] by the largest absolute score,
+    /// indexed by node index. Returns all zeros if every score is zero.
+    pub fn normalized_scores(&self) -> Vec<f64> {
+        let max_abs = self.scores.iter().fold(0.0_f64, |acc, s| acc.max(s.abs()));
+        if max_abs < f64::EPSILON {
+            vec![0.0; self.scores.len()]
+        } else {
+            self.scores.iter().map(|s| s / max_abs).collect()
+        }
+    }
+
+    /// Get the proposal being voted on
+    pub fn get_proposal(&self) -> &DVector<f64> {
+        &self.proposal
+    }
+}
+
+/// Map a score to a vote: yes above threshold, no below -threshold,
+/// abstain in between
+fn threshold_vote(score: f64, threshold: f64) -> i8 {
+    if score > threshold {
+        1
+    } else if score < -threshold {
+        -1
+    } else {
+        0
+    }
+}
+
+/// The opinion a score expresses to others: its sign, but 0.0 for a score
+/// of exactly zero, which `f64::signum` would count as +1
+fn opinion(score: f64) -> f64 {
+    if score == 0.0 { 0.0 } else { score.signum() }
+}
+
+/// Weighted median of (value, weight) pairs, 0.0 if total weight is zero
+fn weighted_median(pairs: &mut [(f64, f64)]) -> f64 {
+    let total: f64 = pairs.iter().map(|&(_, w)| w).sum();
+    if total.abs() <= f64::EPSILON {
+        return 0.0;
+    }
+
+    pairs.sort_by(|a, b| a.0.total_cmp(&b.0));
+    let mut cumulative = 0.0;
+    for &(value, weight) in pairs.iter() {
+        cumulative += weight;
+        if cumulative >= total / 2.0 {
+            return value;
+        }
+    }
+    0.0
+}
+
+/// Standard logistic function
+fn sigmoid(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+/// Every member's ideal must have the proposal's length
+fn check_dimensions(congress: &CongressGraph, proposal: &DVector<f64>) -> Result<(), SimError> {
+    match congress
+        .graph
+        .node_weights()
+        .find(|node| node.ideal.len() != proposal.len())
+    {
+        Some(node) => Err(SimError::DimensionMismatch {
+            member: node.id.clone(),
+            ideal: node.ideal.len(),
+            proposal: proposal.len(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Computes cosine similarity between two vectors
+pub fn cosine_similarity(a: &DVector<f64>, b: &DVector<f64>) -> f64 {
+    let dot_product = a.dot(b);
+    let norm_a = a.norm();
+    let norm_b = b.norm();
+
+    if norm_a.abs() < f64::EPSILON || norm_b.abs() < f64::EPSILON {
+        0.0
+    } else {
+        dot_product / (norm_a * norm_b)
+    }
+}
+
+/// Cosine similarity with per-dimension weights `w`:
+/// `sum(w a b) / sqrt(sum(w a^2) * sum(w b^2))`. Weights should be
+/// non-negative; a dimension with weight 0.0 is ignored.
+pub fn weighted_cosine_similarity(a: &DVector<f64>, b: &DVector<f64>, w: &DVector<f64>) -> f64 {
+    let dot_product = a.component_mul(b).dot(w);
+    let norm_a = a.component_mul(a).dot(w).sqrt();
+    let norm_b = b.component_mul(b).dot(w).sqrt();
+
+    if norm_a.abs() < f64::EPSILON || norm_b.abs() < f64::EPSILON {
+        0.0
+    } else {
+        dot_product / (norm_a * norm_b)
+    }
+}
+
+/// How much closer `proposal` brings policy to `ideal` than `status_quo`,
+/// in [-1, 1]: `(|ideal - status_quo| - |ideal - proposal|) / |proposal -
+/// status_quo|`. Positive means the member prefers the proposal; 1.0 means
+/// the whole move is toward them. 0.0 if the proposal is the status quo.
+pub fn status_quo_alignment(
+    ideal: &DVector<f64>,
+    proposal: &DVector<f64>,
+    status_quo: &DVector<f64>,
+) -> f64 {
+    let step = (proposal - status_quo).norm();
+    if step < f64::EPSILON {
+        0.0
+    } else {
+        ((ideal - status_quo).norm() - (ideal - proposal).norm()) / step
+    }
+}
+
+/// Alignment over mixed dimension kinds, in [-1, 1].
+///
+/// Continuous dimensions are compared with cosine similarity; binary ones by
+/// the mean sign agreement (+1 agree, -1 disagree, 0 if either side is
+/// neutral). The two parts are blended with `binary_weight`, defaulting to
+/// the share of binary dimensions. `kinds` must match the vector length.
+pub fn mixed_alignment(
+    a: &DVector<f64>,
+    b: &DVector<f64>,
+    kinds: &[DimensionKind],
+    binary_weight: Option<f64>,
+) -> f64 {
+    let pick = |v: &DVector<f64>, kind: DimensionKind| -> DVector<f64> {
+        DVector::from_iterator(
+            kinds.iter().filter(|&&k| k == kind).count(),
+            v.iter()
+                .zip(kinds)
+                .filter(|&(_, &k)| k == kind)
+                .map(|(&x, _)| x),
+        )
+    };
+    let (a_cont, b_cont) = (
+        pick(a, DimensionKind::Continuous),
+        pick(b, DimensionKind::Continuous),
+    );
+    let (a_bin, b_bin) = (
+        pick(a, DimensionKind::Binary),
+        pick(b, DimensionKind::Binary),
+    );
+
+    if a_bin.is_empty() {
+        return cosine_similarity(&a_cont, &b_cont);
+    }
+    let agreement = a_bin
+        .iter()
+        .zip(b_bin.iter())
+        .map(|(&x, &y)| {
+            if x == 0.0 || y == 0.0 {
+                0.0
+            } else {
+                x.signum() * y.signum()
+            }
+        })
+        .sum::<f64>()
+        / a_bin.len() as f64;
+    if a_cont.is_empty() {
+        return agreement;
+    }
+
+    let w = binary_weight.unwrap_or(a_bin.len() as f64 / kinds.len() as f64);
+    (1.0 - w) * cosine_similarity(&a_cont, &b_cont) + w * agreement
+}
 
 /// Generate dummy proposal vector, should only be used for test propose
 /// Recevice a dimension and a positive f64 as upper range.
 pub fn gen_random_proposal(ideal_dimension: usize, upper_range: f64) -> DVector<f64> {
-    let mut rng = rand::thread_rng();
+    gen_random_proposal_with_rng(ideal_dimension, upper_range, &mut rand::rng())
+}
+
+/// Same as `gen_random_proposal`, drawing entries from `rng`
+pub fn gen_random_proposal_with_rng<R: Rng + ?Sized>(
+    ideal_dimension: usize,
+    upper_range: f64,
+    rng: &mut R,
+) -> DVector<f64> {
     let data: Vec<f64> = (0..ideal_dimension)
-        .map(|_| rng.gen_range(-upper_range..upper_range))
+        .map(|_| rng.random_range(-upper_range..upper_range))
         .collect();
     DVector::from_vec(data)
 }
 
+/// Synthesize a reproducible random congress for benchmarks and scaling
+/// tests. Members `m0..` get ideals in [-1, 1], bias in [-0.5, 0.5] and
+/// swing in [0, 1], and each joins one of the parties `p0..` (with
+/// discipline in [0, 1]) uniformly at random; with no parties everyone is
+/// independent. Each ordered pair of distinct members gets an edge with
+/// probability `edge_density`, weighted in (0, 1], so about
+/// `edge_density * n * (n - 1)` edges are drawn.
+pub fn gen_random_congress(
+    n_members: usize,
+    n_parties: usize,
+    dim: usize,
+    edge_density: f64,
+    seed: u64,
+) -> CongressGraph {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut cg = CongressGraph::new();
+
+    let members: Vec<NodeIndex> = (0..n_members)
+        .map(|i| {
+            cg.add_node(Node {
+                id: format!("m{}", i),
+                ideal: gen_random_proposal_with_rng(dim, 1.0, &mut rng),
+                bias: rng.random_range(-0.5..=0.5),
+                swing: rng.random_range(0.0..=1.0),
+                credibility: 1.0,
+                threshold: None,
+                conflict_abstain: None,
+                initial_vote: None,
+            })
+        })
+        .collect();
+
+    for &from in &members {
+        for &to in &members {
+            if from != to && rng.random_bool(edge_density.clamp(0.0, 1.0)) {
+                let weight = 1.0 - rng.random_range(0.0..1.0);
+                cg.add_edge(from, to, weight);
+            }
+        }
+    }
+
+    if n_parties > 0 {
+        let mut rosters = vec![Vec::new(); n_parties];
+        for &member in &members {
+            rosters[rng.random_range(0..n_parties)].push(member);
+        }
+        for (i, roster) in rosters.into_iter().enumerate() {
+            cg.add_party(Party {
+                id: format!("p{}", i),
+                discipline: rng.random_range(0.0..=1.0),
+                members: roster,
+                position: None,
+            });
+        }
+    }
+    cg
+}
+
 /*
 example usage(for test only, better load config from toml file)
 use polisimlib::sim::*;
@@ -320,6 +2614,10 @@ let a1 = congress.add_node(Node {
     ideal: DVector::from_vec(vec![1.0, -0.5, 0.0]),
     bias: 0.2,
     swing: 0.7,
+    credibility: 1.0,
+    threshold: None,
+    conflict_abstain: None,
+    initial_vote: None,
 });
 // Add other nodes...
 
@@ -332,6 +2630,7 @@ congress.add_party(Party {
     id: "Party A".into(),
     discipline: 0.8,
     members: vec![a1, a2, a3],
+    position: None,
 });
 // Add other parties...
 
@@ -344,3 +2643,1003 @@ simulator.run(5, 0.1); // 5 rounds, ±0.1 threshold
 println!("A1 vote: {:?}", simulator.get_vote(a1));
 
 */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(id: &str, ideal: &[f64], bias: f64, swing: f64) -> Node {
+        Node {
+            id: id.into(),
+            ideal: DVector::from_row_slice(ideal),
+            bias,
+            swing,
+            credibility: 1.0,
+            threshold: None,
+            conflict_abstain: None,
+            initial_vote: None,
+        }
+    }
+
+    /// `target` (for, swing 0.5) influenced by three opponents who never move
+    fn outnumbered(self_weight: Option<f64>) -> (CongressGraph, NodeIndex) {
+        let mut cg = CongressGraph::new();
+        let target = cg.add_node(member("target", &[1.0], 0.0, 0.5));
+        for id in ["o1", "o2", "o3"] {
+            let opponent = cg.add_node(member(id, &[-1.0], 0.0, 0.0));
+            cg.add_edge(opponent, target, 1.0);
+        }
+        if let Some(weight) = self_weight {
+            cg.add_edge(target, target, weight);
+        }
+        (cg, target)
+    }
+
+    fn declared(mut node: Node, vote: i8) -> Node {
+        node.initial_vote = Some(vote);
+        node
+    }
+
+    #[test]
+    fn strong_self_loop_resists_peer_pressure() {
+        let proposal = DVector::from_vec(vec![1.0]);
+
+        let (cg, target) = outnumbered(None);
+        let mut sim = Simulator::new(&cg, proposal.clone());
+        sim.run_ordered(10, 0.1);
+        assert_eq!(sim.get_vote(target), -1);
+
+        let (cg, target) = outnumbered(Some(10.0));
+        let mut sim = Simulator::new(&cg, proposal);
+        sim.run_ordered(10, 0.1);
+        assert_eq!(sim.get_vote(target), 1);
+        assert!(sim.get_score(target) > 0.5);
+    }
+
+    #[test]
+    fn declared_no_starts_strongly_negative_despite_alignment() {
+        let mut cg = CongressGraph::new();
+        let declared_no = cg.add_node(declared(member("d", &[1.0, 0.0], 0.5, 0.5), -1));
+        let aligned = cg.add_node(member("a", &[1.0, 0.0], 0.5, 0.5));
+
+        let sim = Simulator::new(&cg, DVector::from_vec(vec![1.0, 0.0]));
+        assert_eq!(sim.get_score(declared_no), -DECLARED_VOTE_SCORE);
+        assert!(sim.get_score(declared_no) < -sim.get_score(aligned));
+        assert_eq!(sim.projected_tally(0.1).no, 1);
+    }
+
+    #[test]
+    fn declared_abstainer_exerts_no_opinion() {
+        let mut cg = CongressGraph::new();
+        let abstainer = cg.add_node(declared(member("d", &[1.0], 0.0, 0.0), 0));
+        let follower = cg.add_node(member("f", &[0.0], 0.0, 1.0));
+        let co_partisan = cg.add_node(member("p", &[0.0], 0.0, 1.0));
+        cg.add_edge(abstainer, follower, 1.0);
+        cg.add_party(Party {
+            id: "p".into(),
+            discipline: 1.0,
+            members: vec![abstainer, co_partisan],
+            position: None,
+        });
+
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+        sim.run_ordered(5, 0.1);
+        assert_eq!(sim.get_score(abstainer), 0.0);
+        assert_eq!(sim.get_score(follower), 0.0);
+        assert_eq!(sim.get_score(co_partisan), 0.0);
+    }
+
+    #[test]
+    fn diff_reports_initial_vote_change() {
+        let mut old = CongressGraph::new();
+        old.add_node(member("a", &[1.0], 0.0, 0.5));
+        let mut new = CongressGraph::new();
+        new.add_node(declared(member("a", &[1.0], 0.0, 0.5), 1));
+
+        let diff = old.diff(&new);
+        assert_eq!(
+            diff.changed_members,
+            vec![("a".to_string(), "initial_vote None -> Some(1)".to_string())]
+        );
+    }
+
+    /// Members without edges or parties, so their votes are their baselines
+    fn independents(ideals: &[&[f64]]) -> CongressGraph {
+        let mut cg = CongressGraph::new();
+        for (i, ideal) in ideals.iter().enumerate() {
+            cg.add_node(member(&format!("m{}", i), ideal, 0.0, 0.5));
+        }
+        cg
+    }
+
+    #[test]
+    fn tally_matches_hand_count() {
+        // yes, yes, no, and an undecided member who abstains
+        let cg = independents(&[&[1.0], &[2.0], &[-1.0], &[0.0]]);
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+        sim.run(3, 0.1);
+
+        let tally = sim.tally();
+        assert_eq!(
+            tally,
+            VoteTally {
+                yes: 2,
+                no: 1,
+                abstain: 1
+            }
+        );
+        assert_eq!(tally.total_cast(), 3);
+        assert_eq!(tally.total_all(), 4);
+        assert!(sim.passes(Majority::SIMPLE));
+        assert!(!sim.passes(Majority::ABSSUPER));
+    }
+
+    #[test]
+    fn normalize_incoming_weights_sums_to_one() {
+        let mut cg = independents(&[&[1.0], &[1.0], &[1.0], &[1.0]]);
+        let n = |i| NodeIndex::new(i);
+        cg.add_edge(n(0), n(1), 2.0);
+        cg.add_edge(n(2), n(1), 6.0);
+        cg.add_edge(n(1), n(2), 0.3);
+        cg.add_edge(n(2), n(2), 0.1);
+        cg.normalize_incoming_weights();
+
+        for node_idx in [n(1), n(2)] {
+            let total: f64 = cg
+                .graph
+                .edges_directed(node_idx, petgraph::Direction::Incoming)
+                .map(|e| *e.weight())
+                .sum();
+            assert!((total - 1.0).abs() < 1e-12);
+        }
+        assert_eq!(cg.edge_weight("m0", "m1"), Some(0.25));
+        // m0 and m3 have no incoming edges and are left alone
+        assert_eq!(cg.graph.edge_count(), 4);
+    }
+
+    #[test]
+    fn vote_entropy_of_unanimous_and_even_split() {
+        let proposal = DVector::from_vec(vec![1.0]);
+
+        let cg = independents(&[&[1.0], &[1.0], &[1.0]]);
+        let mut sim = Simulator::new(&cg, proposal.clone());
+        sim.run(1, 0.1);
+        assert_eq!(sim.vote_entropy(), 0.0);
+
+        let cg = independents(&[&[1.0], &[-1.0], &[0.0]]);
+        let mut sim = Simulator::new(&cg, proposal);
+        sim.run(1, 0.1);
+        assert!((sim.vote_entropy() - 3f64.log2()).abs() < 1e-12);
+    }
+
+    /// m0 -> m1 -> ... -> m{n-1}
+    fn chain(n: usize) -> CongressGraph {
+        let ideals = vec![&[1.0][..]; n];
+        let mut cg = independents(&ideals);
+        for i in 1..n {
+            cg.add_edge(NodeIndex::new(i - 1), NodeIndex::new(i), 1.0);
+        }
+        cg
+    }
+
+    #[test]
+    fn influence_closure_follows_chain() {
+        let cg = chain(4);
+        assert_eq!(cg.influence_closure("m0"), vec!["m1", "m2", "m3"]);
+        assert_eq!(cg.influence_closure("m2"), vec!["m3"]);
+        assert!(cg.influence_closure("m3").is_empty());
+        assert!(cg.influence_closure("nobody").is_empty());
+    }
+
+    #[test]
+    fn linear_run_converges_to_linear_equilibrium() {
+        let mut cg = CongressGraph::new();
+        let a = cg.add_node(member("a", &[1.0, 0.0], 0.2, 0.6));
+        let b = cg.add_node(member("b", &[-1.0, 0.5], 0.0, 0.8));
+        let c = cg.add_node(member("c", &[0.3, -1.0], -0.1, 0.4));
+        let d = cg.add_node(member("d", &[0.5, 0.5], 0.0, 0.5));
+        cg.add_edge(a, b, 1.0);
+        cg.add_edge(b, c, 0.5);
+        cg.add_edge(c, a, 2.0);
+        cg.add_edge(d, a, 0.5);
+        cg.add_edge(c, c, 1.0);
+
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![0.8, 0.2]));
+        sim.set_peer_aggregation(PeerAggregation::Linear);
+        let equilibrium = sim.solve_linear_equilibrium().unwrap();
+        sim.run_ordered(200, 0.1);
+        for node_idx in cg.graph.node_indices() {
+            assert!((sim.get_score(node_idx) - equilibrium[node_idx.index()]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn max_aggregation_follows_loudest_influencer() {
+        let mut cg = CongressGraph::new();
+        let target = cg.add_node(member("t", &[0.0], 0.0, 1.0));
+        let loud = cg.add_node(member("loud", &[1.0], 0.0, 0.0));
+        cg.add_edge(loud, target, 5.0);
+        for id in ["q1", "q2", "q3"] {
+            let quiet = cg.add_node(member(id, &[-1.0], 0.0, 0.0));
+            cg.add_edge(quiet, target, 2.0);
+        }
+        let proposal = DVector::from_vec(vec![1.0]);
+
+        let mut sim = Simulator::new(&cg, proposal.clone());
+        sim.run_ordered(1, 0.01);
+        assert_eq!(sim.get_vote(target), -1);
+
+        let mut sim = Simulator::new(&cg, proposal);
+        sim.set_peer_aggregation(PeerAggregation::Max);
+        sim.run_ordered(1, 0.01);
+        assert_eq!(sim.get_vote(target), 1);
+    }
+
+    #[test]
+    fn influence_sccs_finds_three_cycle() {
+        let mut cg = chain(4);
+        cg.add_edge(NodeIndex::new(2), NodeIndex::new(0), 1.0);
+
+        let mut components: Vec<Vec<String>> = cg
+            .influence_sccs()
+            .into_iter()
+            .map(|mut component| {
+                component.sort();
+                component
+            })
+            .collect();
+        components.sort();
+        assert_eq!(components, vec![vec!["m0", "m1", "m2"], vec!["m3"]]);
+    }
+
+    #[test]
+    fn orthogonal_member_is_undecided() {
+        let cg = independents(&[&[1.0, 0.0], &[0.0, 1.0], &[-1.0, 0.1]]);
+        let sim = Simulator::new(&cg, DVector::from_vec(vec![1.0, 0.0]));
+        assert_eq!(sim.undecided_members(0.05), vec!["m1"]);
+    }
+
+    #[test]
+    fn mixed_alignment_counts_binary_agreement() {
+        let ideal = DVector::from_vec(vec![1.0, 1.0, -1.0]);
+        let proposal = DVector::from_vec(vec![0.5, 1.0, 1.0]);
+        let kinds = [
+            DimensionKind::Continuous,
+            DimensionKind::Binary,
+            DimensionKind::Binary,
+        ];
+
+        // Continuous part agrees fully, the binary votes split 1:1
+        let mixed = mixed_alignment(&ideal, &proposal, &kinds, None);
+        assert!((mixed - 1.0 / 3.0).abs() < 1e-12);
+        let cosine = cosine_similarity(&ideal, &proposal);
+        assert!((cosine - 0.5 / (3f64.sqrt() * 1.5)).abs() < 1e-12);
+        assert!((mixed_alignment(&ideal, &proposal, &kinds, Some(0.5)) - 0.5).abs() < 1e-12);
+
+        let mut cg = CongressGraph::new();
+        assert_eq!(cg.alignment(&ideal, &proposal), cosine);
+        cg.set_dimension_kinds(kinds.to_vec(), None);
+        assert_eq!(cg.alignment(&ideal, &proposal), mixed);
+    }
+
+    #[test]
+    fn empty_congress_runs_and_fails() {
+        let cg = CongressGraph::new();
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+        sim.run(5, 0.1);
+        assert!(!sim.passes(Majority::SIMPLE));
+        assert!(!sim.passes(Majority::UNANIMITY));
+        assert_eq!(sim.tally(), VoteTally::default());
+    }
+
+    #[test]
+    fn try_new_rejects_empty_congress() {
+        let cg = CongressGraph::new();
+        let err = Simulator::try_new(&cg, DVector::from_vec(vec![1.0])).err();
+        assert_eq!(err, Some(SimError::EmptyGraph));
+    }
+
+    #[test]
+    fn quorum_invalidates_thin_votes() {
+        // Two yes, one abstain: passes a simple majority with 2 or 3 present
+        let cg = independents(&[&[1.0], &[1.0], &[0.0]]);
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+        sim.run(1, 0.1);
+        assert!(sim.passes(Majority::SIMPLE));
+
+        assert!(sim.passes_with_quorum(Majority::SIMPLE, 2, false));
+        assert!(!sim.passes_with_quorum(Majority::SIMPLE, 3, false));
+        assert!(sim.passes_with_quorum(Majority::SIMPLE, 3, true));
+        assert!(!sim.passes_with_quorum(Majority::SIMPLE, 4, true));
+    }
+
+    #[test]
+    fn conditional_edges_are_gated_per_edge() {
+        let mut cg = independents(&[&[1.0, 0.0], &[0.0, 0.0], &[0.0, 1.0]]);
+        let (a, b, c) = (NodeIndex::new(0), NodeIndex::new(1), NodeIndex::new(2));
+        let on_first = DVector::from_vec(vec![1.0, 0.0]);
+        let on_second = DVector::from_vec(vec![0.0, 1.0]);
+
+        // Parallel edges: only the conditional one is gated
+        cg.add_conditional_edge(a, b, 1.0, vec![1]);
+        cg.add_edge(a, b, 1.0);
+        assert!(!cg.edge_is_active(EdgeIndex::new(0), &on_first));
+        assert!(cg.edge_is_active(EdgeIndex::new(1), &on_first));
+        assert!(cg.edge_is_active(EdgeIndex::new(0), &on_second));
+
+        // Removing an edge moves the last one into its slot, condition included
+        cg.add_conditional_edge(c, b, 1.0, vec![0]);
+        assert!(cg.remove_edge(a, b));
+        assert!(cg.remove_edge(a, b));
+        let moved = cg.graph.find_edge(c, b).unwrap();
+        assert!(cg.edge_is_active(moved, &on_first));
+        assert!(!cg.edge_is_active(moved, &on_second));
+    }
+
+    #[test]
+    fn inactive_edge_exerts_no_pressure() {
+        let mut cg = independents(&[&[1.0, 0.0], &[0.0, 0.0]]);
+        let (leader, follower) = (NodeIndex::new(0), NodeIndex::new(1));
+        cg.add_conditional_edge(leader, follower, 1.0, vec![1]);
+
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0, 0.0]));
+        sim.run_ordered(3, 0.1);
+        assert_eq!(sim.get_score(follower), 0.0);
+
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0, 0.5]));
+        sim.run_ordered(3, 0.1);
+        assert_eq!(sim.get_vote(follower), 1);
+    }
+
+    #[test]
+    fn clamp_bounds_diverging_scores() {
+        // A negative edge from a neutral member makes a and b amplify each
+        // other's raw score twofold every update
+        let mut cg = CongressGraph::new();
+        let a = cg.add_node(member("a", &[1.0], 0.0, 1.0));
+        let b = cg.add_node(member("b", &[1.0], 0.0, 1.0));
+        let c = cg.add_node(member("c", &[0.0], 0.0, 1.0));
+        for (from, to, weight) in [(b, a, 2.0), (c, a, -1.0), (a, b, 2.0), (c, b, -1.0)] {
+            cg.add_edge(from, to, weight);
+        }
+        let proposal = DVector::from_vec(vec![1.0]);
+
+        let mut sim = Simulator::new(&cg, proposal.clone());
+        sim.set_peer_aggregation(PeerAggregation::Linear);
+        sim.run_ordered(30, 0.1);
+        assert!(sim.get_score(a) > 1e6);
+
+        let mut sim = Simulator::new(&cg, proposal);
+        sim.set_peer_aggregation(PeerAggregation::Linear);
+        sim.set_clamp(Some((-1.0, 1.0)));
+        sim.run_ordered(30, 0.1);
+        for node_idx in cg.graph.node_indices() {
+            assert!((-1.0..=1.0).contains(&sim.get_score(node_idx)));
+        }
+    }
+
+    #[test]
+    fn proximity_pulls_edgeless_allies_together() {
+        // Identical ideals, but bias puts a against and b, c for
+        let mut cg = CongressGraph::new();
+        let a = cg.add_node(member("a", &[1.0, 0.1], -0.3, 0.5));
+        cg.add_node(member("b", &[1.0, 0.1], 0.3, 0.5));
+        cg.add_node(member("c", &[1.0, 0.1], 0.3, 0.5));
+        let proposal = DVector::from_vec(vec![0.0, 1.0]);
+
+        let mut sim = Simulator::new(&cg, proposal.clone());
+        sim.run_ordered(20, 0.1);
+        assert!(sim.get_score(a) < 0.0);
+
+        let mut sim = Simulator::new(&cg, proposal);
+        sim.set_proximity_influence(1.0);
+        sim.run_ordered(20, 0.1);
+        let scores: Vec<f64> = cg.graph.node_indices().map(|n| sim.get_score(n)).collect();
+        let spread = scores.iter().copied().fold(f64::MIN, f64::max)
+            - scores.iter().copied().fold(f64::MAX, f64::min);
+        assert!(spread < 1e-3);
+        assert_eq!(sim.tally().yes, 3);
+    }
+
+    /// Undecided independents whose scores are exactly their biases
+    fn biased(biases: &[f64]) -> CongressGraph {
+        let mut cg = CongressGraph::new();
+        for (i, &bias) in biases.iter().enumerate() {
+            cg.add_node(member(&format!("m{}", i), &[0.0], bias, 0.5));
+        }
+        cg
+    }
+
+    #[test]
+    fn score_histogram_counts_bins() {
+        let cg = biased(&[-2.0, -0.5, 0.1, 0.2, 0.9, 3.0]);
+        let sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+        // Out-of-range scores land in the end bins
+        assert_eq!(sim.score_histogram(4, (-1.0, 1.0)), vec![1, 1, 2, 2]);
+        assert!(sim.score_histogram(0, (-1.0, 1.0)).is_empty());
+    }
+
+    #[test]
+    fn node_index_looks_up_members() {
+        let mut cg = CongressGraph::new();
+        let a = cg.add_node(member("a", &[1.0], 0.0, 0.5));
+        let b = cg.add_node(member("b", &[1.0], 0.0, 0.5));
+        assert_eq!(cg.node_index("a"), Some(a));
+        assert_eq!(cg.node_index("b"), Some(b));
+        assert_eq!(cg.node_index("c"), None);
+    }
+
+    #[test]
+    fn vote_block_votes_together() {
+        let mut cg = biased(&[0.5, 0.3, -0.2, -0.4]);
+        let proposal = DVector::from_vec(vec![1.0]);
+        let mut sim = Simulator::new(&cg, proposal.clone());
+        sim.run(0, 0.1);
+        assert_eq!(sim.get_vote(NodeIndex::new(2)), -1);
+
+        // Mean score of the block is 0.2, above the threshold
+        cg.add_vote_block((0..3).map(NodeIndex::new).collect());
+        let mut sim = Simulator::new(&cg, proposal);
+        sim.run(0, 0.1);
+        let votes: Vec<i8> = cg.graph.node_indices().map(|n| sim.get_vote(n)).collect();
+        assert_eq!(votes, vec![1, 1, 1, -1]);
+    }
+
+    #[test]
+    fn steep_soft_abstain_concentrates_on_low_conviction() {
+        // 100 members at |score| 0.05 followed by 100 at |score| 0.8
+        let biases: Vec<f64> = (0..200)
+            .map(|i| {
+                let conviction = if i < 100 { 0.05 } else { 0.8 };
+                if i % 2 == 0 { conviction } else { -conviction }
+            })
+            .collect();
+        let cg = biased(&biases);
+        let low_share = |steepness: f64| {
+            let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+            sim.run_soft_abstain(0, 0.4, steepness, &mut StdRng::seed_from_u64(3));
+            let abstainers: Vec<usize> = cg
+                .graph
+                .node_indices()
+                .filter(|&n| sim.get_vote(n) == 0)
+                .map(|n| n.index())
+                .collect();
+            abstainers.iter().filter(|&&i| i < 100).count() as f64 / abstainers.len() as f64
+        };
+
+        let (gentle, steep) = (low_share(1.0), low_share(20.0));
+        assert!(gentle < 0.8);
+        assert!(steep > 0.99);
+    }
+
+    #[test]
+    fn diff_lists_exactly_the_changes() {
+        let build = |edited: bool| {
+            let mut cg = CongressGraph::new();
+            let a = cg.add_node(member("a", &[1.0], if edited { 0.3 } else { 0.0 }, 0.5));
+            let b = cg.add_node(member("b", &[1.0], 0.0, 0.5));
+            let other = cg.add_node(member(if edited { "d" } else { "c" }, &[1.0], 0.0, 0.5));
+            cg.add_edge(a, b, if edited { 0.9 } else { 0.5 });
+            cg.add_edge(b, other, 1.0);
+            let members = if edited { vec![a, b] } else { vec![a] };
+            cg.add_party(Party {
+                id: "p".into(),
+                discipline: 0.5,
+                members,
+                position: None,
+            });
+            cg
+        };
+        let diff = build(false).diff(&build(true));
+
+        assert_eq!(diff.added_members, vec!["d"]);
+        assert_eq!(diff.removed_members, vec!["c"]);
+        assert_eq!(
+            diff.changed_members,
+            vec![("a".to_string(), "bias 0 -> 0.3".to_string())]
+        );
+        let s = |x: &str| x.to_string();
+        assert_eq!(diff.added_edges, vec![(s("b"), s("d"), 1.0)]);
+        assert_eq!(diff.removed_edges, vec![(s("b"), s("c"), 1.0)]);
+        assert_eq!(diff.reweighted_edges, vec![(s("a"), s("b"), 0.5, 0.9)]);
+        assert_eq!(diff.party_changes, vec![(s("b"), None, Some(s("p")))]);
+        assert!(build(false).diff(&build(false)).is_empty());
+    }
+
+    #[test]
+    fn ema_damps_oscillation() {
+        // a follows b while b opposes a, so with full swing they flip forever;
+        // the neutral z only balances b's total incoming weight to 1
+        let mut cg = CongressGraph::new();
+        let a = cg.add_node(member("a", &[1.0], 0.0, 1.0));
+        let b = cg.add_node(member("b", &[1.0], 0.0, 1.0));
+        let z = cg.add_node(member("z", &[0.0], 0.0, 1.0));
+        cg.add_edge(b, a, 1.0);
+        cg.add_edge(a, b, -1.0);
+        cg.add_edge(z, b, 2.0);
+
+        let swing_of_a = |alpha: f64| {
+            let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+            sim.set_order_mode(OrderMode::Fixed);
+            sim.set_track_history(true);
+            sim.run_with_ema(20, 0.1, alpha);
+            let history = sim.history().unwrap();
+            (11..20)
+                .map(|r| (history[r][a.index()] - history[r - 1][a.index()]).abs())
+                .sum::<f64>()
+        };
+        assert!(swing_of_a(1.0) > 10.0);
+        assert!(swing_of_a(0.3) < swing_of_a(1.0) / 2.0);
+    }
+
+    #[test]
+    fn party_rebels_lists_the_dissenter() {
+        let mut cg = biased(&[0.5, 0.5, -0.5, 0.0, -0.5]);
+        cg.add_party(Party {
+            id: "p".into(),
+            discipline: 0.0,
+            members: (0..4).map(NodeIndex::new).collect(),
+            position: None,
+        });
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+        sim.run(0, 0.1);
+        // m3 abstains and m4 is an independent, so neither is a rebel
+        assert_eq!(sim.party_rebels(), vec!["m2"]);
+    }
+
+    #[test]
+    fn personal_threshold_overrides_run_threshold() {
+        let mut cg = CongressGraph::new();
+        let cautious = cg.add_node(Node {
+            threshold: Some(0.8),
+            ..member("cautious", &[0.0], 0.5, 0.5)
+        });
+        let decided = cg.add_node(member("decided", &[0.0], 0.5, 0.5));
+
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+        sim.run(0, 0.1);
+        assert_eq!(sim.get_vote(cautious), 0);
+        assert_eq!(sim.get_vote(decided), 1);
+    }
+
+    #[test]
+    fn subgraph_keeps_only_committee_edges() {
+        let mut cg = chain(4);
+        let n = NodeIndex::new;
+        cg.add_edge(n(2), n(0), 0.5);
+        cg.add_party(Party {
+            id: "p".into(),
+            discipline: 0.5,
+            members: vec![n(1), n(2), n(3)],
+            position: None,
+        });
+        let committee = cg.subgraph(&["m0".to_string(), "m2".to_string()]).unwrap();
+
+        assert_eq!(committee.graph.node_count(), 2);
+        assert_eq!(committee.graph.edge_count(), 1);
+        assert_eq!(committee.edge_weight("m2", "m0"), Some(0.5));
+        assert_eq!(committee.parties().len(), 1);
+        assert_eq!(
+            committee.parties()[0].members,
+            vec![committee.node_index("m2").unwrap()]
+        );
+        assert_eq!(
+            cg.subgraph(&["m9".to_string()]).err(),
+            Some(SimError::UnknownMember("m9".into()))
+        );
+    }
+
+    #[test]
+    fn naive_votes_match_zero_round_run() {
+        let cg = gen_random_congress(30, 3, 3, 0.2, 5);
+        let proposal = DVector::from_vec(vec![0.4, -0.7, 0.2]);
+        let mut sim = Simulator::new(&cg, proposal.clone());
+        sim.run(0, 0.2);
+        assert_eq!(cg.naive_votes(&proposal, 0.2), sim.get_votes());
+    }
+
+    #[test]
+    fn simulate_bundle_is_consistent() {
+        let cg = gen_random_congress(25, 2, 2, 0.2, 11);
+        let proposal = DVector::from_vec(vec![0.6, -0.3]);
+        let result = cg.simulate(proposal.clone(), 5, 0.1, Majority::SIMPLE, 4);
+
+        let mut sim = Simulator::new(&cg, proposal);
+        sim.run_with_rng(5, 0.1, &mut StdRng::seed_from_u64(4));
+        assert_eq!(result.passed, sim.passes(Majority::SIMPLE));
+        assert_eq!(
+            result.tally,
+            VoteTally::from_votes(result.votes.values().copied())
+        );
+        assert_eq!(result.votes.len(), 25);
+        assert_eq!(result.scores.len(), 25);
+        assert_eq!(result.social_welfare, sim.social_welfare());
+        assert_eq!(result.polarization, sim.polarization("p0", "p1"));
+    }
+
+    #[test]
+    fn copartisan_boost_favors_party_ally() {
+        let mut cg = CongressGraph::new();
+        let target = cg.add_node(member("t", &[0.0], 0.0, 0.5));
+        let ally = cg.add_node(member("ally", &[1.0], 0.0, 0.0));
+        let rival = cg.add_node(member("rival", &[-1.0], 0.0, 0.0));
+        cg.add_edge(ally, target, 1.0);
+        cg.add_edge(rival, target, 1.0);
+        cg.add_party(Party {
+            id: "p".into(),
+            discipline: 0.0,
+            members: vec![target, ally],
+            position: None,
+        });
+        let proposal = DVector::from_vec(vec![1.0]);
+
+        let mut sim = Simulator::new(&cg, proposal.clone());
+        sim.run_ordered(5, 0.1);
+        assert_eq!(sim.get_score(target), 0.0);
+
+        let mut sim = Simulator::new(&cg, proposal);
+        sim.set_order_mode(OrderMode::Fixed);
+        sim.run_with_copartisan_boost(5, 0.1, 3.0);
+        assert_eq!(sim.get_vote(target), 1);
+    }
+
+    #[test]
+    fn update_member_recomputes_only_that_member() {
+        let cg = gen_random_congress(10, 2, 2, 0.3, 2);
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![0.5, 0.5]));
+        let before = sim.initial_scores().to_vec();
+
+        let edited = NodeIndex::new(3);
+        let node = cg.graph[edited].clone();
+        sim.update_member(edited, node.bias + 0.25, node.swing, node.ideal)
+            .unwrap();
+        for (i, (&old, &new)) in before.iter().zip(sim.initial_scores()).enumerate() {
+            if i == edited.index() {
+                assert!((new - old - 0.25).abs() < 1e-12);
+            } else {
+                assert_eq!(new, old);
+            }
+        }
+        assert_eq!(sim.get_score(edited), sim.initial_scores()[edited.index()]);
+    }
+
+    #[test]
+    fn status_quo_member_supports_a_move_toward_them() {
+        let mut cg = CongressGraph::new();
+        let m = cg.add_node(member("m", &[-0.2], 0.0, 0.5));
+        let proposal = DVector::from_vec(vec![0.5]);
+
+        // Against the origin the proposal points the other way
+        let mut sim = Simulator::new(&cg, proposal.clone());
+        sim.run(0, 0.1);
+        assert_eq!(sim.get_vote(m), -1);
+
+        // Against a far status quo it is a step toward the member's ideal
+        let mut sim = Simulator::new_with_status_quo(&cg, proposal, DVector::from_vec(vec![2.0]));
+        sim.run(0, 0.1);
+        assert!(sim.get_score(m) > 0.9);
+        assert_eq!(sim.get_vote(m), 1);
+    }
+
+    #[test]
+    fn run_ordered_is_deterministic() {
+        let cg = gen_random_congress(30, 3, 2, 0.2, 11);
+        let proposal = DVector::from_vec(vec![0.3, -0.6]);
+        let scores = || {
+            let mut sim = Simulator::new(&cg, proposal.clone());
+            sim.run_ordered(25, 0.01);
+            cg.graph
+                .node_indices()
+                .map(|n| sim.get_score(n))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(scores(), scores());
+    }
+
+    #[test]
+    fn effective_num_parties_reflects_dominance() {
+        let two_parties = |split: usize| {
+            let mut cg = biased(&[0.0; 10]);
+            let (a, b): (Vec<_>, Vec<_>) = cg.graph.node_indices().partition(|n| n.index() < split);
+            for (id, members) in [("a", a), ("b", b)] {
+                cg.add_party(Party {
+                    id: id.into(),
+                    discipline: 0.5,
+                    members,
+                    position: None,
+                });
+            }
+            cg.effective_num_parties()
+        };
+        assert!((two_parties(5) - 2.0).abs() < 1e-12);
+        // 0.8^2 + 0.2^2 = 0.68
+        assert!((two_parties(8) - 1.0 / 0.68).abs() < 1e-12);
+        assert!(two_parties(8) < 2.0);
+    }
+
+    #[test]
+    fn external_pressure_flips_a_swing_member() {
+        let cg = biased(&[0.5, 0.3, -0.2, -0.4]);
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+        sim.run(0, 0.1);
+        assert!(!sim.passes(Majority::SIMPLE));
+
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+        sim.apply_external_pressure(&HashMap::from([("m2".to_string(), 0.5)]))
+            .unwrap();
+        sim.run(0, 0.1);
+        assert_eq!(sim.get_vote(NodeIndex::new(2)), 1);
+        assert!(sim.passes(Majority::SIMPLE));
+
+        let unknown = HashMap::from([("nobody".to_string(), 1.0)]);
+        assert!(sim.apply_external_pressure(&unknown).is_err());
+    }
+
+    #[test]
+    fn locked_abstention_exerts_no_opinion() {
+        let mut cg = CongressGraph::new();
+        let leader = cg.add_node(member("l", &[1.0], 0.0, 0.5));
+        let follower = cg.add_node(member("f", &[0.0], 0.0, 1.0));
+        cg.add_edge(leader, follower, 1.0);
+        let proposal = DVector::from_vec(vec![1.0]);
+
+        let mut sim = Simulator::new(&cg, proposal.clone());
+        sim.run_ordered(5, 0.1);
+        assert!(sim.get_score(follower) > 0.0);
+
+        let mut sim = Simulator::new(&cg, proposal);
+        sim.pin_vote_locked(leader, 0);
+        sim.run_ordered(5, 0.1);
+        assert_eq!(sim.get_score(leader), 0.0);
+        assert_eq!(sim.get_score(follower), 0.0);
+    }
+
+    #[test]
+    fn vote_outcome_margin_measures_closeness() {
+        let proposal = DVector::from_vec(vec![1.0]);
+        let cg = biased(&[0.5, 0.5, 0.5, 0.5, -0.5]);
+        let mut sim = Simulator::new(&cg, proposal.clone());
+        sim.run(0, 0.1);
+        let comfortable = sim.vote_outcome(Majority::SIMPLE);
+        assert!(comfortable.passed);
+        assert!((comfortable.margin - 0.3).abs() < 1e-12);
+
+        let mut biases = vec![0.5; 4];
+        biases.extend([-0.5; 5]);
+        let cg = biased(&biases);
+        let mut sim = Simulator::new(&cg, proposal);
+        sim.run(0, 0.1);
+        let narrow = sim.vote_outcome(Majority::SIMPLE);
+        assert!(!narrow.passed);
+        assert!(narrow.margin < 0.0 && narrow.margin > -0.1);
+        assert_eq!((narrow.yes, narrow.no, narrow.abstain), (4, 5, 0));
+    }
+
+    #[test]
+    fn evenly_split_member_feels_balanced_party_pressure() {
+        let split = |weights: [f64; 2]| {
+            let mut cg = CongressGraph::new();
+            let member_idx = cg.add_node(member("m", &[0.0], 0.0, 1.0));
+            for (id, stance, weight) in [("yes", 1.0, weights[0]), ("no", -1.0, weights[1])] {
+                cg.add_party_with_weights(
+                    Party {
+                        id: id.into(),
+                        discipline: 0.8,
+                        members: vec![member_idx],
+                        position: Some(DVector::from_vec(vec![stance])),
+                    },
+                    vec![weight],
+                );
+            }
+            let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+            let pressure = sim.calculate_party_pressure(member_idx, None);
+            sim.run_ordered(5, 0.1);
+            (pressure, sim.get_score(member_idx))
+        };
+        assert_eq!(split([1.0, 1.0]), (0.0, 0.0));
+
+        let (pressure, score) = split([3.0, 1.0]);
+        assert!((pressure - 0.4).abs() < 1e-12);
+        assert!(score > 0.0);
+    }
+
+    #[test]
+    fn random_congress_has_expected_size_and_density() {
+        let cg = gen_random_congress(100, 3, 2, 0.1, 5);
+        let stats = cg.stats();
+        assert_eq!(stats.members, 100);
+        assert_eq!(stats.parties, 3);
+        assert_eq!(stats.independents, 0);
+        // Expect 0.1 * 100 * 99 = 990 edges, give or take a few deviations
+        assert!(
+            (stats.edges as f64 - 990.0).abs() < 150.0,
+            "{} edges",
+            stats.edges
+        );
+        assert_eq!(
+            gen_random_congress(100, 3, 2, 0.1, 5).stats().edges,
+            stats.edges
+        );
+    }
+
+    #[test]
+    fn edge_weight_by_id_round_trips() {
+        let mut cg = independents(&[&[1.0], &[1.0]]);
+        assert_eq!(cg.edge_weight("m0", "m1"), None);
+
+        cg.set_edge_weight_by_id("m0", "m1", 0.4).unwrap();
+        assert_eq!(cg.edge_weight("m0", "m1"), Some(0.4));
+        cg.set_edge_weight_by_id("m0", "m1", 0.7).unwrap();
+        assert_eq!(cg.edge_weight("m0", "m1"), Some(0.7));
+        assert_eq!(cg.graph.edge_count(), 1);
+        assert_eq!(cg.edge_weight("m1", "m0"), None);
+
+        assert!(matches!(
+            cg.set_edge_weight_by_id("m0", "nobody", 1.0),
+            Err(SimError::UnknownMember(id)) if id == "nobody"
+        ));
+        assert_eq!(cg.edge_weight("m0", "nobody"), None);
+    }
+
+    #[test]
+    fn support_probabilities_follow_scores() {
+        let cg = biased(&[3.0, 0.0, -3.0]);
+        let sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+        let probs = sim.support_probabilities(5.0);
+        assert!(probs["m0"] > 0.999);
+        assert_eq!(probs["m1"], 0.5);
+        assert!(probs["m2"] < 0.001);
+    }
+
+    #[test]
+    fn frozen_members_vote_as_frozen() {
+        let cg = biased(&[-0.5, -0.4, -0.3]);
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+        sim.freeze_vote("m0", 1).unwrap();
+        sim.freeze_vote("m1", 1).unwrap();
+        assert!(sim.freeze_vote("nobody", 1).is_err());
+        sim.run(0, 0.1);
+
+        let votes = sim.get_votes();
+        assert_eq!((votes["m0"], votes["m1"], votes["m2"]), (1, 1, -1));
+        assert!(sim.get_score(NodeIndex::new(0)) < 0.0);
+        assert!(sim.passes(Majority::SIMPLE));
+    }
+
+    #[test]
+    fn cached_party_lines_match_naive_recomputation() {
+        // Overlapping weighted memberships, plus a party with a fixed position
+        let mut cg = gen_random_congress(20, 0, 2, 0.2, 17);
+        let party = |id: &str, members: Vec<usize>, position: Option<Vec<f64>>| Party {
+            id: id.into(),
+            discipline: 0.9,
+            members: members.into_iter().map(NodeIndex::new).collect(),
+            position: position.map(DVector::from_vec),
+        };
+        cg.add_party(party("left", (0..12).collect(), None));
+        let right: Vec<usize> = (6..20).collect();
+        let weights = right.iter().map(|i| 0.5 + (i % 3) as f64).collect();
+        cg.add_party_with_weights(party("right", right, None), weights);
+        cg.add_party(party(
+            "platform",
+            (0..20).step_by(3).collect(),
+            Some(vec![0.7, -0.2]),
+        ));
+
+        let proposal = DVector::from_vec(vec![0.4, 0.9]);
+        let mut cached = Simulator::new(&cg, proposal.clone());
+        cached.set_order_mode(OrderMode::Fixed);
+        let mut naive = Simulator::new(&cg, proposal);
+        let initial_signs: Vec<f64> = cached.scores.iter().map(|&s| opinion(s)).collect();
+
+        for _ in 0..6 {
+            cached.step();
+            // Fixed-order round recomputing every party line from live scores
+            for node_idx in cg.graph.node_indices() {
+                let pressure = naive.calculate_peer_pressure(node_idx)
+                    + naive.calculate_party_pressure(node_idx, None)
+                    + naive.calculate_proximity_pressure(node_idx)
+                    + naive.calculate_salience_pressure(node_idx);
+                naive.update_node_score(node_idx, pressure);
+            }
+            naive.rounds_done += 1;
+
+            for (c, n) in cached.scores.iter().zip(&naive.scores) {
+                assert!((c - n).abs() < 1e-12, "cached {} vs naive {}", c, n);
+            }
+        }
+        // The cache was exercised: some members changed sides along the way
+        assert!(
+            cached
+                .scores
+                .iter()
+                .zip(&initial_signs)
+                .any(|(&s, &sign)| opinion(s) != sign)
+        );
+    }
+
+    #[test]
+    fn custom_majority_threshold() {
+        let rule = |count_abstentions| Majority::Custom {
+            fraction: 0.6,
+            count_abstentions,
+        };
+        let tally = |yes, no, abstain| VoteTally { yes, no, abstain };
+        assert!(tally(61, 39, 0).passes(rule(false)));
+        assert!(!tally(59, 41, 0).passes(rule(false)));
+
+        // 59 of 96 cast is over 60%, but 59 of 100 seated is not
+        assert!(tally(59, 37, 4).passes(rule(false)));
+        assert!(!tally(59, 37, 4).passes(rule(true)));
+        assert!(tally(61, 35, 4).passes(rule(true)));
+    }
+
+    #[test]
+    fn update_member_rejects_bad_ideal_and_swing() {
+        let cg = independents(&[&[1.0, 0.0], &[0.0, 1.0]]);
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0, 0.0]));
+        let m0 = NodeIndex::new(0);
+        let before = sim.get_score(m0);
+
+        assert_eq!(
+            sim.update_member(m0, 0.0, 0.5, DVector::from_vec(vec![1.0])),
+            Err(SimError::DimensionMismatch {
+                member: "m0".into(),
+                ideal: 1,
+                proposal: 2
+            })
+        );
+        assert_eq!(
+            sim.update_member(m0, 0.0, 1.5, DVector::from_vec(vec![0.0, 1.0])),
+            Err(SimError::SwingOutOfRange {
+                member: "m0".into(),
+                swing: 1.5
+            })
+        );
+        assert_eq!(sim.get_score(m0), before);
+    }
+
+    #[test]
+    fn damped_scores_converge() {
+        // With no influence each score halves every round, so the largest
+        // change in round k is 0.5^(k + 1)
+        let cg = biased(&[0.5, -0.5, 0.25]);
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+        let mut rng = StdRng::seed_from_u64(1);
+        assert_eq!(
+            sim.run_until_converged(50, 0.1, 1e-3, &mut rng),
+            ConvergenceStatus::Converged { rounds: 9 }
+        );
+
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+        assert_eq!(
+            sim.run_until_converged(5, 0.1, 1e-3, &mut rng),
+            ConvergenceStatus::MaxRoundsReached
+        );
+    }
+
+    #[test]
+    fn flip_flop_cycle_oscillates() {
+        // `a` follows `b`, while `b` is pushed against `a` through a negative
+        // edge offset by a silent member, so `b` flips sign every round
+        let mut cg = CongressGraph::new();
+        let a = cg.add_node(member("a", &[0.0], 1.0, 1.0));
+        let b = cg.add_node(member("b", &[0.0], 1.0, 1.0));
+        let silent = cg.add_node(member("c", &[0.0], 0.0, 0.0));
+        cg.add_edge(b, a, 1.0);
+        cg.add_edge(a, b, -1.0);
+        cg.add_edge(silent, b, 2.0);
+
+        let mut sim = Simulator::new(&cg, DVector::from_vec(vec![1.0]));
+        sim.set_order_mode(OrderMode::Fixed);
+        let mut rng = StdRng::seed_from_u64(1);
+        // Every round changes some score by 2, so the smallest change stops
+        // shrinking after round 1
+        assert_eq!(
+            sim.run_until_converged(50, 0.1, 1e-3, &mut rng),
+            ConvergenceStatus::Oscillating {
+                detected_round: 1 + OSCILLATION_WINDOW
+            }
+        );
+    }
+}
@@ -0,0 +1,74 @@
+//! Browser bindings, enabled by the `wasm` feature.
+//!
+//! The library itself builds as an rlib only, so native users never compile
+//! a cdylib. Build the WebAssembly module by requesting the cdylib for this
+//! one invocation, then generate the JavaScript glue with `wasm-bindgen`:
+//!
+//! ```text
+//! cargo rustc -p libpolisim --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//! wasm-bindgen --target web --out-dir pkg target/wasm32-unknown-unknown/release/libpolisim.wasm
+//! ```
+
+use nalgebra::DVector;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use wasm_bindgen::prelude::*;
+
+use crate::loader::load_congress_graph_from_str;
+use crate::sim::{Majority, Simulator};
+
+/// Parse a rule name as accepted by the CLI (`simple`, `super`,
+/// `abs-simple`, `abs-super`, `unanimity`)
+fn parse_rule(rule: &str) -> Result<Majority, String> {
+    match rule {
+        "simple" => Ok(Majority::SIMPLE),
+        "super" => Ok(Majority::SUPER),
+        "abs-simple" => Ok(Majority::ABSSIMPLE),
+        "abs-super" => Ok(Majority::ABSSUPER),
+        "unanimity" => Ok(Majority::UNANIMITY),
+        _ => Err(format!("Unknown rule `{}`", rule)),
+    }
+}
+
+fn simulate(
+    config_json: &str,
+    proposal: &[f64],
+    rounds: usize,
+    threshold: f64,
+    rule: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let rule = parse_rule(rule)?;
+    // Same schema as the TOML config, so route it through the TOML loader
+    let config: toml::Value = serde_json::from_str(config_json)?;
+    let congress = load_congress_graph_from_str(&toml::to_string(&config)?)?;
+
+    let dim = congress.ideal_dimension().unwrap_or(0);
+    if proposal.len() != dim {
+        return Err(format!(
+            "Proposal has length {}, but ideal_dimension = {}",
+            proposal.len(),
+            dim
+        )
+        .into());
+    }
+
+    let mut sim = Simulator::try_new(&congress, DVector::from_column_slice(proposal))?;
+    // Fixed seed: the browser has no OS entropy source by default
+    sim.run_with_rng(rounds, threshold, &mut StdRng::seed_from_u64(0));
+    Ok(serde_json::to_string(&sim.result(rule))?)
+}
+
+/// Run one simulation on a JSON config (same fields as the TOML config) and
+/// return the `SimulationResult` as JSON, or `{"error": "..."}` on failure.
+/// The update order is seeded, so identical inputs give identical output.
+#[wasm_bindgen]
+pub fn simulate_from_json(
+    config_json: &str,
+    proposal: &[f64],
+    rounds: usize,
+    threshold: f64,
+    rule: &str,
+) -> String {
+    simulate(config_json, proposal, rounds, threshold, rule)
+        .unwrap_or_else(|e| serde_json::json!({ "error": e.to_string() }).to_string())
+}
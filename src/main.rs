@@ -1,7 +1,13 @@
-use clap::{Parser, ValueEnum};
-use libpolisim::loader::load_congress_graph_from_toml;
-use libpolisim::sim::{Majority, Simulator, gen_random_proposal};
+use clap::error::ErrorKind;
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use libpolisim::analysis::{for_each_random_proposal, monte_carlo_pass_rate};
+use libpolisim::loader::{
+    LoaderOptions, load_congress_graph_from_toml, load_congress_graph_from_toml_with_options,
+    load_proposal_from_file, validate_config,
+};
+use libpolisim::sim::{CongressGraph, Majority, Simulator, gen_random_proposal};
 use nalgebra::DVector;
+use std::io::Write;
 
 /// Simple CLI for running congressional simulations.
 #[derive(Parser)]
@@ -10,9 +16,38 @@ use nalgebra::DVector;
 #[command(about = "Run a legislative simulation using libpolisim", long_about = None)]
 struct Cli {
     /// Path to the TOML config describing members, parties, and edges
-    #[arg(short, long)]
-    config: String,
+    #[arg(short, long, global = true)]
+    config: Option<String>,
 
+    /// Print a summary of the loaded graph (to stderr) before running the command
+    #[arg(long, global = true)]
+    stats: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single simulation and print the final votes
+    Simulate(SimulateArgs),
+    /// Load the config and report potential problems without simulating
+    Diagnose,
+    /// Run every config check and exit non-zero if any error is found
+    Validate,
+    /// Export the influence graph in Graphviz DOT format
+    Export {
+        /// Write to this file instead of stdout
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+    /// Run many random proposals and report how often they pass
+    MonteCarlo(MonteCarloArgs),
+}
+
+/// Parameters shared by every mode that runs the simulation
+#[derive(Args)]
+struct RunArgs {
     /// Number of rounds to simulate social influence
     #[arg(long, default_value_t = 5)]
     rounds: usize,
@@ -21,16 +56,53 @@ struct Cli {
     #[arg(short, long, default_value_t = 0.1)]
     threshold: f64,
 
-    /// Maximum absolute value for random proposal vector entries,
-    /// Should be the same as "ideal_dimension" field you declared in toml.
-    #[arg(long)]
-    range: f64,
-
     /// Majority rule to decide if the proposal passes
     #[arg(short, long, value_enum, default_value_t = Rule::Simple)]
     rule: Rule,
 }
 
+#[derive(Args)]
+struct SimulateArgs {
+    #[command(flatten)]
+    run: RunArgs,
+
+    /// Maximum absolute value for random proposal vector entries; required
+    /// unless --proposal-file is given
+    #[arg(long, required_unless_present = "proposal_file")]
+    range: Option<f64>,
+
+    /// File holding the proposal vector (one float per line, or an array),
+    /// used instead of a random proposal
+    #[arg(long)]
+    proposal_file: Option<String>,
+
+    /// Write every member's score after each round to this CSV file
+    #[arg(long)]
+    trajectory_csv: Option<String>,
+}
+
+#[derive(Args)]
+struct MonteCarloArgs {
+    #[command(flatten)]
+    run: RunArgs,
+
+    /// Maximum absolute value for random proposal vector entries
+    #[arg(long)]
+    range: f64,
+
+    /// Number of random proposals to simulate
+    #[arg(long, default_value_t = 100)]
+    trials: usize,
+
+    /// Stream one JSON result per proposal to stdout instead of a summary
+    #[arg(long)]
+    jsonl: bool,
+
+    /// Seed for reproducible JSON Lines output (random if omitted)
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
 /// We map our internal Majority enum to clap-friendly variants
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum, Debug)]
 enum Rule {
@@ -54,41 +126,217 @@ impl From<Rule> for Majority {
 }
 
 fn main() -> anyhow::Result<()> {
+    env_logger::init();
     let cli = Cli::parse();
+    // Global arguments cannot be required, so check for it by hand
+    let Some(config) = cli.config else {
+        Cli::command()
+            .error(
+                ErrorKind::MissingRequiredArgument,
+                "the following required arguments were not provided:\n  --config <CONFIG>",
+            )
+            .exit();
+    };
 
-    let mut congress = load_congress_graph_from_toml(&cli.config)
-        .map_err(|e| anyhow::anyhow!("Failed to load config: {}", e))?;
+    match cli.command {
+        Command::Diagnose => diagnose(&config, cli.stats),
+        Command::Validate => {
+            if cli.stats {
+                eprintln!("{}\n", load(&config)?.stats());
+            }
+            let report = validate_config(&config);
+            for error in &report.errors {
+                println!("error: {}", error);
+            }
+            for warning in &report.warnings {
+                println!("warning: {}", warning);
+            }
+            if !report.is_ok() {
+                anyhow::bail!("{} error(s) found in {}", report.errors.len(), config);
+            }
+            println!("{} is valid", config);
+            Ok(())
+        }
+        command => {
+            let congress = load(&config)?;
+            if cli.stats {
+                eprintln!("{}\n", congress.stats());
+            }
+            run_command(&congress, command)
+        }
+    }
+}
+
+/// Run a command that works on the strictly loaded graph
+fn run_command(congress: &CongressGraph, command: Command) -> anyhow::Result<()> {
+    match command {
+        Command::Simulate(args) => simulate(congress, &args),
+        Command::Export { output } => {
+            let dot = congress.to_dot();
+            match output {
+                Some(path) => std::fs::write(path, dot)?,
+                None => print!("{}", dot),
+            }
+            Ok(())
+        }
+        Command::MonteCarlo(args) if args.jsonl => {
+            let mut stdout = std::io::stdout().lock();
+            let mut write_error = None;
+            for_each_random_proposal(
+                congress,
+                args.trials,
+                args.range,
+                args.run.rounds,
+                args.run.threshold,
+                args.run.rule.into(),
+                args.seed.unwrap_or_else(rand::random),
+                |result| {
+                    // Keep the first failure (e.g. a closed pipe) and skip the rest
+                    if write_error.is_none() {
+                        let line = serde_json::to_string(result).expect("result serializes");
+                        write_error = writeln!(stdout, "{}", line).err();
+                    }
+                },
+            );
+            write_error.map_or(Ok(()), |e| Err(e.into()))
+        }
+        Command::MonteCarlo(args) => {
+            let rate = monte_carlo_pass_rate(
+                congress,
+                args.range,
+                args.trials,
+                args.run.rounds,
+                args.run.threshold,
+                args.run.rule.into(),
+            );
+            println!(
+                "Pass rate under rule {:?}: {:.1}% of {} proposals",
+                args.run.rule,
+                rate * 100.0,
+                args.trials
+            );
+            Ok(())
+        }
+        Command::Diagnose | Command::Validate => {
+            unreachable!("these commands load the config themselves")
+        }
+    }
+}
+
+fn load(config: &str) -> anyhow::Result<CongressGraph> {
+    load_congress_graph_from_toml(config)
+        .map_err(|e| anyhow::anyhow!("Failed to load config: {}", e))
+}
 
+fn simulate(congress: &CongressGraph, args: &SimulateArgs) -> anyhow::Result<()> {
     let dim = congress
-        .graph
-        .node_weights()
-        .next()
-        .map(|n| n.ideal.len())
+        .ideal_dimension()
         .ok_or_else(|| anyhow::anyhow!("No members in graph"))?;
 
-    let proposal: DVector<f64> = gen_random_proposal(dim, cli.range);
-    println!("Using random proposal: {}", proposal);
+    let proposal: DVector<f64> = match (&args.proposal_file, args.range) {
+        (Some(path), _) => {
+            let proposal = load_proposal_from_file(path)
+                .map_err(|e| anyhow::anyhow!("Failed to load proposal: {}", e))?;
+            if proposal.len() != dim {
+                anyhow::bail!(
+                    "Proposal has length {}, but ideal_dimension = {}",
+                    proposal.len(),
+                    dim
+                );
+            }
+            println!("Using proposal from {}: {}", path, proposal);
+            proposal
+        }
+        (None, Some(range)) => {
+            let proposal = gen_random_proposal(dim, range);
+            println!("Using random proposal: {}", proposal);
+            proposal
+        }
+        (None, None) => unreachable!("clap requires --range without --proposal-file"),
+    };
+
+    let mut sim = Simulator::new(congress, proposal);
+    sim.set_track_history(args.trajectory_csv.is_some());
+    sim.run(args.run.rounds, args.run.threshold);
 
-    let mut sim = Simulator::new(&congress, proposal);
-    sim.run(cli.rounds, cli.threshold);
+    if let (Some(path), Some(history)) = (&args.trajectory_csv, sim.history()) {
+        write_trajectory_csv(path, congress, history)?;
+    }
 
     println!("\nFinal votes:");
-    for (id, vote) in sim.get_votes().iter() {
+    for (id, score, vote) in sim.ranked_members() {
         let sign = match vote {
             1 => "YES",
             0 => "ABSTAIN",
             -1 => "NO",
             _ => unreachable!(),
         };
-        println!("  {:<15} → {}", id, sign);
+        println!("  {:<15} → {:<7} ({:+.3})", id, sign, score);
     }
 
-    let passed = sim.passes(cli.rule.into());
+    let passed = sim.passes(args.run.rule.into());
     println!(
         "\nProposal {} under rule {:?}",
         if passed { "PASSED" } else { "FAILED" },
-        cli.rule
+        args.run.rule
+    );
+
+    Ok(())
+}
+
+/// One row per round with a column per member, sorted by member ID
+fn write_trajectory_csv(
+    path: &str,
+    congress: &CongressGraph,
+    history: &[Vec<f64>],
+) -> anyhow::Result<()> {
+    let mut members: Vec<_> = congress.graph.node_indices().collect();
+    members.sort_by(|&a, &b| congress.graph[a].id.cmp(&congress.graph[b].id));
+
+    let mut csv = String::from("round");
+    for &member in &members {
+        csv.push(',');
+        csv.push_str(&congress.graph[member].id);
+    }
+    csv.push('\n');
+
+    for (round, scores) in history.iter().enumerate() {
+        csv.push_str(&(round + 1).to_string());
+        for &member in &members {
+            csv.push_str(&format!(",{}", scores[member.index()]));
+        }
+        csv.push('\n');
+    }
+
+    std::fs::write(path, csv)?;
+    Ok(())
+}
+
+fn diagnose(config: &str, stats: bool) -> anyhow::Result<()> {
+    let options = LoaderOptions {
+        strict_party_members: false,
+        ..LoaderOptions::default()
+    };
+    let (congress, warnings) = load_congress_graph_from_toml_with_options(config, &options)
+        .map_err(|e| anyhow::anyhow!("Failed to load config: {}", e))?;
+
+    if stats {
+        eprintln!("{}\n", congress.stats());
+    }
+    println!(
+        "Loaded {} members, {} edges, {} parties",
+        congress.graph.node_count(),
+        congress.graph.edge_count(),
+        congress.parties().len()
     );
+    for warning in &warnings {
+        println!("warning: {}", warning);
+    }
+    for (node_idx, member) in congress.members() {
+        if congress.get_party_index(node_idx).is_none() {
+            println!("note: member `{}` belongs to no party", member.id);
+        }
+    }
 
     Ok(())
 }
@@ -0,0 +1,117 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+const CONFIG: &str = r#"
+ideal_dimension = 2
+
+[[congress_members]]
+id = "a"
+ideal = [1.0, 0.0]
+bias = 0.0
+swing = 0.5
+
+[[congress_members]]
+id = "b"
+ideal = [0.0, 1.0]
+bias = 0.0
+swing = 0.5
+
+[[edges]]
+from = "a"
+to = "b"
+weight = 0.5
+
+[[parties]]
+id = "p"
+discipline = 0.5
+members = ["a", "b"]
+"#;
+
+/// A scratch directory unique to this test, holding `config.toml`
+fn scratch(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("polisim-cli-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("config.toml"), CONFIG).unwrap();
+    dir
+}
+
+fn polisim(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_polisim-cli"))
+        .arg("--config")
+        .arg(dir.join("config.toml"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn proposal_file_is_used() {
+    let dir = scratch("proposal-file");
+    let proposal = dir.join("proposal.txt");
+    fs::write(&proposal, "0.25\n-0.75\n").unwrap();
+
+    let out = polisim(
+        &dir,
+        &["simulate", "--proposal-file", proposal.to_str().unwrap()],
+    );
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    assert!(stdout.contains(&format!("Using proposal from {}", proposal.display())));
+    assert!(stdout.contains("0.25") && stdout.contains("-0.75"));
+
+    fs::write(&proposal, "[0.25, -0.75, 1.0]").unwrap();
+    let out = polisim(
+        &dir,
+        &["simulate", "--proposal-file", proposal.to_str().unwrap()],
+    );
+    assert!(!out.status.success());
+    assert!(String::from_utf8_lossy(&out.stderr).contains("Proposal has length 3"));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn trajectory_csv_has_a_row_per_round() {
+    let dir = scratch("trajectory");
+    let csv = dir.join("trajectory.csv");
+
+    let out = polisim(
+        &dir,
+        &[
+            "simulate",
+            "--range",
+            "1.0",
+            "--rounds",
+            "4",
+            "--trajectory-csv",
+            csv.to_str().unwrap(),
+        ],
+    );
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    let content = fs::read_to_string(&csv).unwrap();
+    let lines: Vec<&str> = content.lines().collect();
+    assert_eq!(lines.len(), 4 + 1);
+    assert_eq!(lines[0], "round,a,b");
+    assert!(lines.iter().all(|line| line.split(',').count() == 3));
+    fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn missing_config_is_a_usage_error() {
+    let out = Command::new(env!("CARGO_BIN_EXE_polisim-cli"))
+        .arg("export")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert_eq!(out.status.code(), Some(2), "stderr: {}", stderr);
+    assert!(stderr.contains("--config <CONFIG>"), "stderr: {}", stderr);
+    assert!(stderr.contains("Usage:"), "stderr: {}", stderr);
+}